@@ -0,0 +1,33 @@
+// Helpers for the batch transforms, which run on multiple threads when the "rayon" feature is enabled
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+// Slices shorter than this are not worth splitting between threads
+#[cfg(feature = "rayon")]
+const MIN_ELEMENTS_PER_TASK: usize = 4096;
+
+// Replace every element of a slice with the output of a function
+pub(crate) fn map_in_place<T, F>(slice: &mut [T], function: F)
+where
+    T: Copy + Send + Sync,
+    F: Fn(T) -> T + Send + Sync,
+{
+    #[cfg(feature = "rayon")]
+    slice.par_iter_mut().with_min_len(MIN_ELEMENTS_PER_TASK).for_each(|element| *element = function(*element));
+    #[cfg(not(feature = "rayon"))]
+    slice.iter_mut().for_each(|element| *element = function(*element));
+}
+
+// Write the output of a function for every element of the source slice into the destination slice
+pub(crate) fn map_into<S, D, F>(src: &[S], dst: &mut [D], function: F)
+where
+    S: Copy + Send + Sync,
+    D: Send,
+    F: Fn(S) -> D + Send + Sync,
+{
+    assert_eq!(src.len(), dst.len(), "source and destination slices have different lengths");
+    #[cfg(feature = "rayon")]
+    dst.par_iter_mut().zip(src.par_iter()).with_min_len(MIN_ELEMENTS_PER_TASK).for_each(|(output, input)| *output = function(*input));
+    #[cfg(not(feature = "rayon"))]
+    dst.iter_mut().zip(src.iter()).for_each(|(output, input)| *output = function(*input));
+}
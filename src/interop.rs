@@ -0,0 +1,7 @@
+// Conversions from and to the types of other math crates, each behind its own feature
+#[cfg(feature = "glam")]
+mod glam;
+#[cfg(feature = "mint")]
+mod mint;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
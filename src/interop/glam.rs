@@ -0,0 +1,65 @@
+use crate::{
+    matrix::Matrix4x4,
+    quaternion::Quaternion,
+    vectors::{Vector2, Vector3, Vector4},
+};
+
+// Vectors, for every element type that glam supports
+macro_rules! impl_glam_vector {
+    ($t: ident, $glam: ident, $v: ty, [$($c: ident),*]) => {
+        impl From<glam::$glam> for $t<$v> {
+            fn from(vector: glam::$glam) -> Self {
+                $t::new($(vector.$c),*)
+            }
+        }
+        impl From<$t<$v>> for glam::$glam {
+            fn from(vector: $t<$v>) -> Self {
+                glam::$glam::new($(vector.$c),*)
+            }
+        }
+    };
+}
+impl_glam_vector!(Vector2, Vec2, f32, [x, y]);
+impl_glam_vector!(Vector3, Vec3, f32, [x, y, z]);
+impl_glam_vector!(Vector4, Vec4, f32, [x, y, z, w]);
+impl_glam_vector!(Vector2, DVec2, f64, [x, y]);
+impl_glam_vector!(Vector3, DVec3, f64, [x, y, z]);
+impl_glam_vector!(Vector4, DVec4, f64, [x, y, z, w]);
+impl_glam_vector!(Vector2, IVec2, i32, [x, y]);
+impl_glam_vector!(Vector3, IVec3, i32, [x, y, z]);
+impl_glam_vector!(Vector4, IVec4, i32, [x, y, z, w]);
+impl_glam_vector!(Vector2, UVec2, u32, [x, y]);
+impl_glam_vector!(Vector3, UVec3, u32, [x, y, z]);
+impl_glam_vector!(Vector4, UVec4, u32, [x, y, z, w]);
+
+// Matrices and quaternions. glam is collumn major and stores quaternions as [x, y, z, w] too, so nothing gets reordered
+macro_rules! impl_glam_matrix_quaternion {
+    ($f: ty, $mat4: ident, $vec4: ident, $quat: ident) => {
+        impl From<glam::$mat4> for Matrix4x4<$f> {
+            fn from(matrix: glam::$mat4) -> Self {
+                Matrix4x4 {
+                    data: matrix.to_cols_array_2d().map(|[x, y, z, w]| Vector4::new(x, y, z, w)),
+                }
+            }
+        }
+        impl From<Matrix4x4<$f>> for glam::$mat4 {
+            fn from(matrix: Matrix4x4<$f>) -> Self {
+                let [x, y, z, w] = matrix.data.map(glam::$vec4::from);
+                glam::$mat4::from_cols(x, y, z, w)
+            }
+        }
+        impl From<glam::$quat> for Quaternion<$f> {
+            fn from(quaternion: glam::$quat) -> Self {
+                let [x, y, z, w] = quaternion.to_array();
+                Quaternion::from_xyzw(x, y, z, w)
+            }
+        }
+        impl From<Quaternion<$f>> for glam::$quat {
+            fn from(quaternion: Quaternion<$f>) -> Self {
+                glam::$quat::from_xyzw(quaternion.x(), quaternion.y(), quaternion.z(), quaternion.w())
+            }
+        }
+    };
+}
+impl_glam_matrix_quaternion!(f32, Mat4, Vec4, Quat);
+impl_glam_matrix_quaternion!(f64, DMat4, DVec4, DQuat);
@@ -0,0 +1,89 @@
+use crate::{
+    matrix::{Matrix2x2, Matrix3x3, Matrix4x4},
+    quaternion::Quaternion,
+    types::SupportedValue,
+    vectors::{Vector2, Vector3, Vector4},
+};
+
+// Vectors
+impl<T> From<mint::Vector2<T>> for Vector2<T> {
+    fn from(vector: mint::Vector2<T>) -> Self {
+        Vector2::new(vector.x, vector.y)
+    }
+}
+impl<T> From<Vector2<T>> for mint::Vector2<T> {
+    fn from(vector: Vector2<T>) -> Self {
+        mint::Vector2 { x: vector.x, y: vector.y }
+    }
+}
+impl<T> From<mint::Vector3<T>> for Vector3<T> {
+    fn from(vector: mint::Vector3<T>) -> Self {
+        Vector3::new(vector.x, vector.y, vector.z)
+    }
+}
+impl<T> From<Vector3<T>> for mint::Vector3<T> {
+    fn from(vector: Vector3<T>) -> Self {
+        mint::Vector3 { x: vector.x, y: vector.y, z: vector.z }
+    }
+}
+impl<T> From<mint::Vector4<T>> for Vector4<T> {
+    fn from(vector: mint::Vector4<T>) -> Self {
+        Vector4::new(vector.x, vector.y, vector.z, vector.w)
+    }
+}
+impl<T> From<Vector4<T>> for mint::Vector4<T> {
+    fn from(vector: Vector4<T>) -> Self {
+        mint::Vector4 {
+            x: vector.x,
+            y: vector.y,
+            z: vector.z,
+            w: vector.w,
+        }
+    }
+}
+
+// Quaternions, where mint stores the vector part and the scalar part separately
+impl<T: SupportedValue> From<mint::Quaternion<T>> for Quaternion<T> {
+    fn from(quaternion: mint::Quaternion<T>) -> Self {
+        Quaternion::from_xyzw(quaternion.v.x, quaternion.v.y, quaternion.v.z, quaternion.s)
+    }
+}
+impl<T: SupportedValue> From<Quaternion<T>> for mint::Quaternion<T> {
+    fn from(quaternion: Quaternion<T>) -> Self {
+        mint::Quaternion {
+            v: quaternion.xyz().into(),
+            s: quaternion.w(),
+        }
+    }
+}
+
+// Matrices. veclib matrices are collumn major, so the row major conversions need a transpose
+macro_rules! impl_mint_matrix {
+    ($t: ident, $v: ident, $collumn: ident, $row: ident, [$($field: ident),*]) => {
+        impl<T: SupportedValue> From<mint::$collumn<T>> for $t<T> {
+            fn from(matrix: mint::$collumn<T>) -> Self {
+                $t { data: [$($v::from(matrix.$field)),*] }
+            }
+        }
+        impl<T: SupportedValue> From<$t<T>> for mint::$collumn<T> {
+            fn from(matrix: $t<T>) -> Self {
+                let [$($field),*] = matrix.data;
+                mint::$collumn { $($field: $field.into()),* }
+            }
+        }
+        impl<T: SupportedValue> From<mint::$row<T>> for $t<T> {
+            fn from(matrix: mint::$row<T>) -> Self {
+                $t { data: [$($v::from(matrix.$field)),*] }.transposed()
+            }
+        }
+        impl<T: SupportedValue> From<$t<T>> for mint::$row<T> {
+            fn from(matrix: $t<T>) -> Self {
+                let [$($field),*] = matrix.transposed().data;
+                mint::$row { $($field: $field.into()),* }
+            }
+        }
+    };
+}
+impl_mint_matrix!(Matrix4x4, Vector4, ColumnMatrix4, RowMatrix4, [x, y, z, w]);
+impl_mint_matrix!(Matrix3x3, Vector3, ColumnMatrix3, RowMatrix3, [x, y, z]);
+impl_mint_matrix!(Matrix2x2, Vector2, ColumnMatrix2, RowMatrix2, [x, y]);
@@ -0,0 +1,74 @@
+use crate::{
+    matrix::Matrix4x4,
+    quaternion::Quaternion,
+    types::SupportedValue,
+    vectors::{Vector2, Vector3, Vector4},
+};
+use nalgebra::{ArrayStorage, Scalar};
+
+// Vectors
+impl<T: Scalar> From<nalgebra::Vector2<T>> for Vector2<T> {
+    fn from(vector: nalgebra::Vector2<T>) -> Self {
+        let [[x, y]] = vector.data.0;
+        Vector2::new(x, y)
+    }
+}
+impl<T: Scalar> From<Vector2<T>> for nalgebra::Vector2<T> {
+    fn from(vector: Vector2<T>) -> Self {
+        nalgebra::Vector2::new(vector.x, vector.y)
+    }
+}
+impl<T: Scalar> From<nalgebra::Vector3<T>> for Vector3<T> {
+    fn from(vector: nalgebra::Vector3<T>) -> Self {
+        let [[x, y, z]] = vector.data.0;
+        Vector3::new(x, y, z)
+    }
+}
+impl<T: Scalar> From<Vector3<T>> for nalgebra::Vector3<T> {
+    fn from(vector: Vector3<T>) -> Self {
+        nalgebra::Vector3::new(vector.x, vector.y, vector.z)
+    }
+}
+impl<T: Scalar> From<nalgebra::Vector4<T>> for Vector4<T> {
+    fn from(vector: nalgebra::Vector4<T>) -> Self {
+        let [[x, y, z, w]] = vector.data.0;
+        Vector4::new(x, y, z, w)
+    }
+}
+impl<T: Scalar> From<Vector4<T>> for nalgebra::Vector4<T> {
+    fn from(vector: Vector4<T>) -> Self {
+        nalgebra::Vector4::new(vector.x, vector.y, vector.z, vector.w)
+    }
+}
+
+// Matrices, which nalgebra also stores collumn after collumn
+impl<T: Scalar + SupportedValue> From<nalgebra::Matrix4<T>> for Matrix4x4<T> {
+    fn from(matrix: nalgebra::Matrix4<T>) -> Self {
+        Matrix4x4 {
+            data: matrix.data.0.map(|[x, y, z, w]| Vector4::new(x, y, z, w)),
+        }
+    }
+}
+impl<T: Scalar + SupportedValue> From<Matrix4x4<T>> for nalgebra::Matrix4<T> {
+    fn from(matrix: Matrix4x4<T>) -> Self {
+        nalgebra::Matrix4::from_data(ArrayStorage(matrix.data.map(|collumn| [collumn.x, collumn.y, collumn.z, collumn.w])))
+    }
+}
+
+// Quaternions. nalgebra::Quaternion::new takes the scalar part (W) first, but the underlying storage is [x, y, z, w]
+impl<T: Scalar + SupportedValue> From<nalgebra::Quaternion<T>> for Quaternion<T> {
+    fn from(quaternion: nalgebra::Quaternion<T>) -> Self {
+        let [[x, y, z, w]] = quaternion.coords.data.0;
+        Quaternion::from_xyzw(x, y, z, w)
+    }
+}
+impl<T: Scalar + SupportedValue> From<Quaternion<T>> for nalgebra::Quaternion<T> {
+    fn from(quaternion: Quaternion<T>) -> Self {
+        nalgebra::Quaternion::from_vector(Vector4::new(quaternion.x(), quaternion.y(), quaternion.z(), quaternion.w()).into())
+    }
+}
+impl<T: Scalar + SupportedValue> From<nalgebra::UnitQuaternion<T>> for Quaternion<T> {
+    fn from(quaternion: nalgebra::UnitQuaternion<T>) -> Self {
+        quaternion.into_inner().into()
+    }
+}
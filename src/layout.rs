@@ -0,0 +1,207 @@
+use crate::{
+    matrix::{Matrix2x2, Matrix3x3, Matrix4x4},
+    quaternion::Quaternion,
+    types::SupportedValue,
+    vector::Vector,
+    vectors::{Vector2, Vector3, Vector4},
+};
+use std::mem::size_of;
+
+// The memory layout rules of GLSL/WGSL buffers
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BufferLayout {
+    // Uniform buffers, where arrays (and matrix collumns) are always aligned to 16 bytes
+    Std140,
+    // Storage buffers, which only use the natural alignment of each element
+    Std430,
+}
+
+// Round an offset up to the next multiple of the alignment
+const fn align_to(offset: usize, alignment: usize) -> usize {
+    offset.next_multiple_of(alignment)
+}
+
+// A scalar type that can be stored inside a GPU buffer
+pub trait BufferScalar: Copy {
+    // Append the bytes of the scalar using native endianness
+    fn write_native(self, buffer: &mut Vec<u8>);
+}
+macro_rules! impl_buffer_scalar {
+    ($($t: ty),*) => {
+        $(
+            impl BufferScalar for $t {
+                fn write_native(self, buffer: &mut Vec<u8>) {
+                    buffer.extend_from_slice(&self.to_ne_bytes());
+                }
+            }
+        )*
+    };
+}
+impl_buffer_scalar!(f32, f64, i32, u32);
+
+// Something that can be written into a GPU buffer following the std140 or std430 rules
+pub trait WriteToBuffer {
+    // The alignment of the value inside the buffer, in bytes
+    fn alignment(layout: BufferLayout) -> usize;
+    // The size of the value inside the buffer, in bytes (without any trailing padding)
+    fn size(layout: BufferLayout) -> usize;
+    // Append the bytes of the value, assuming that the buffer is already correctly aligned
+    fn write_packed(&self, layout: BufferLayout, buffer: &mut Vec<u8>);
+    // Pad the buffer to the alignment of the value, write the value, and return the offset it got written at
+    fn write_to_buffer(&self, layout: BufferLayout, buffer: &mut Vec<u8>) -> usize {
+        let offset = align_to(buffer.len(), Self::alignment(layout));
+        buffer.resize(offset, 0);
+        self.write_packed(layout, buffer);
+        offset
+    }
+}
+
+// The alignment and stride of the elements of an array. In std140, both get rounded up to 16 bytes
+fn array_alignment<T: WriteToBuffer>(layout: BufferLayout) -> usize {
+    match layout {
+        BufferLayout::Std140 => align_to(T::alignment(layout), 16),
+        BufferLayout::Std430 => T::alignment(layout),
+    }
+}
+fn array_stride<T: WriteToBuffer>(layout: BufferLayout) -> usize {
+    align_to(T::size(layout), array_alignment::<T>(layout))
+}
+// Write the elements of an array one after the other, padding each one to the array stride
+fn write_packed_array<T: WriteToBuffer>(values: &[T], layout: BufferLayout, buffer: &mut Vec<u8>) {
+    let start = buffer.len();
+    for (i, value) in values.iter().enumerate() {
+        buffer.resize(start + i * array_stride::<T>(layout), 0);
+        value.write_packed(layout, buffer);
+    }
+}
+
+// Arrays
+impl<T: WriteToBuffer, const N: usize> WriteToBuffer for [T; N] {
+    fn alignment(layout: BufferLayout) -> usize {
+        array_alignment::<T>(layout)
+    }
+    fn size(layout: BufferLayout) -> usize {
+        N * array_stride::<T>(layout)
+    }
+    fn write_packed(&self, layout: BufferLayout, buffer: &mut Vec<u8>) {
+        let start = buffer.len();
+        write_packed_array(self, layout, buffer);
+        buffer.resize(start + Self::size(layout), 0);
+    }
+}
+
+// Scalars
+impl<T: BufferScalar> WriteToBuffer for T {
+    fn alignment(_layout: BufferLayout) -> usize {
+        size_of::<T>()
+    }
+    fn size(_layout: BufferLayout) -> usize {
+        size_of::<T>()
+    }
+    fn write_packed(&self, _layout: BufferLayout, buffer: &mut Vec<u8>) {
+        self.write_native(buffer);
+    }
+}
+
+// Vectors. Note that 3D vectors are aligned like 4D vectors, but they are still only 3 elements big
+macro_rules! impl_write_vector {
+    ($t: ident, $alignment: expr) => {
+        impl<T: BufferScalar> WriteToBuffer for $t<T> {
+            fn alignment(_layout: BufferLayout) -> usize {
+                $alignment * size_of::<T>()
+            }
+            fn size(_layout: BufferLayout) -> usize {
+                size_of::<Self>()
+            }
+            fn write_packed(&self, _layout: BufferLayout, buffer: &mut Vec<u8>) {
+                // Safe since the vectors are #[repr(C)] and the buffer scalars are plain old data
+                buffer.extend_from_slice(unsafe { self.to_native_bytes() });
+            }
+        }
+    };
+}
+impl_write_vector!(Vector2, 2);
+impl_write_vector!(Vector3, 4);
+impl_write_vector!(Vector4, 4);
+
+// Matrices, which are stored like an array of collumn vectors
+macro_rules! impl_write_matrix {
+    ($t: ident, $v: ident, $collumns: expr) => {
+        impl<T: BufferScalar + SupportedValue> WriteToBuffer for $t<T> {
+            fn alignment(layout: BufferLayout) -> usize {
+                array_alignment::<$v<T>>(layout)
+            }
+            fn size(layout: BufferLayout) -> usize {
+                <[$v<T>; $collumns]>::size(layout)
+            }
+            fn write_packed(&self, layout: BufferLayout, buffer: &mut Vec<u8>) {
+                self.data.write_packed(layout, buffer);
+            }
+        }
+    };
+}
+impl_write_matrix!(Matrix4x4, Vector4, 4);
+impl_write_matrix!(Matrix3x3, Vector3, 3);
+impl_write_matrix!(Matrix2x2, Vector2, 2);
+
+// Quaternions, which are stored like a 4D vector ([x, y, z, w])
+impl<T: BufferScalar + SupportedValue> WriteToBuffer for Quaternion<T> {
+    fn alignment(layout: BufferLayout) -> usize {
+        Vector4::<T>::alignment(layout)
+    }
+    fn size(layout: BufferLayout) -> usize {
+        Vector4::<T>::size(layout)
+    }
+    fn write_packed(&self, layout: BufferLayout, buffer: &mut Vec<u8>) {
+        Vector4::new(self.x(), self.y(), self.z(), self.w()).write_packed(layout, buffer);
+    }
+}
+
+// A 3D vector padded to 16 bytes, to be used inside #[repr(C)] uniform block structs
+// The same layout is used by std140 and std430
+#[derive(Default, PartialEq, Debug, Clone, Copy)]
+#[repr(C, align(16))]
+pub struct Std140Vec3 {
+    pub value: Vector3<f32>,
+    padding: f32,
+}
+
+impl Std140Vec3 {
+    // Create a new padded vector
+    pub const fn new(value: Vector3<f32>) -> Self {
+        Self { value, padding: 0.0 }
+    }
+}
+impl From<Vector3<f32>> for Std140Vec3 {
+    fn from(value: Vector3<f32>) -> Self {
+        Self::new(value)
+    }
+}
+impl From<Std140Vec3> for Vector3<f32> {
+    fn from(vector: Std140Vec3) -> Self {
+        vector.value
+    }
+}
+
+// A 3x3 matrix with each collumn padded to 16 bytes, to be used inside #[repr(C)] uniform block structs
+// The same layout is used by std140 and std430
+#[derive(Default, PartialEq, Debug, Clone, Copy)]
+#[repr(C, align(16))]
+pub struct Std140Mat3 {
+    pub collumns: [Std140Vec3; 3],
+}
+
+impl From<Matrix3x3<f32>> for Std140Mat3 {
+    fn from(matrix: Matrix3x3<f32>) -> Self {
+        Self {
+            collumns: matrix.data.map(Std140Vec3::new),
+        }
+    }
+}
+impl From<Std140Mat3> for Matrix3x3<f32> {
+    fn from(matrix: Std140Mat3) -> Self {
+        Matrix3x3 {
+            data: matrix.collumns.map(|collumn| collumn.value),
+        }
+    }
+}
@@ -1,14 +1,30 @@
 // Math library
+mod batch;
+mod interop;
+mod layout;
 mod macros;
 mod matrix;
+mod matrixn;
+#[cfg(feature = "bytemuck")]
+mod pod;
 mod quaternion;
+#[cfg(feature = "serde")]
+mod serialization;
+mod shapes;
+#[cfg(feature = "simd")]
+mod simd;
 mod tests;
 mod types;
 mod vector;
 mod vectors;
 // Export the types
+pub use layout::*;
 pub use matrix::*;
+pub use matrixn::*;
+#[cfg(feature = "bytemuck")]
+pub use pod::*;
 pub use quaternion::*;
+pub use shapes::*;
 pub use types::SupportedValue;
 pub use vector::*;
 pub use vectors::*;
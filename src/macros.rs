@@ -49,390 +49,170 @@ macro_rules! impl_default_state {
     };
 }
 
+// Element wise operators for Vector2, Vector3 and Vector4, which go through VectorN
 #[macro_export]
-macro_rules! setup_add {
-    ($t:ty, $a:tt) => {
-        impl<T> Add for $t
+macro_rules! setup_vector_operator {
+    ($t:ty, $trait:ident, $fn:ident, $assign_trait:ident, $assign_fn:ident, $op:tt) => {
+        impl<T> $trait for $t
         where
-            T: Copy + Add<Output = T>,
+            T: Copy + $trait<Output = T>,
         {
             type Output = $t;
 
-            fn add(mut self, rhs: Self) -> Self::Output {
-                for i in 0..Self::ELEM_COUNT {
-                    self[i] = self[i] + rhs[i];
-                }
-                return self;
+            fn $fn(self, rhs: Self) -> Self::Output {
+                ($crate::VectorN::from(self) $op $crate::VectorN::from(rhs)).into()
             }
         }
-        impl<T> Add for &$t
+        impl<T> $trait for &$t
         where
-            T: Copy + Add<Output = T>,
+            T: Copy + $trait<Output = T>,
         {
             type Output = $t;
 
-            fn add(self, rhs: Self) -> Self::Output {
-                let mut me = *self;
-                let rhs = *rhs;
-                for i in 0..Self::ELEM_COUNT {
-                    me[i] = me[i] + rhs[i];
-                }
-                return me;
+            fn $fn(self, rhs: Self) -> Self::Output {
+                *self $op *rhs
             }
         }
-        impl<T> Add<T> for &$t
+        impl<T> $trait<T> for $t
         where
-            T: Copy + Add<Output = T>,
+            T: Copy + $trait<Output = T>,
         {
             type Output = $t;
 
-            fn add(self, rhs: T) -> Self::Output {
-                let mut me = *self;
-                let rhs = rhs;
-                for i in 0..Self::ELEM_COUNT {
-                    me[i] = me[i] + rhs;
-                }
-                return me;
+            fn $fn(self, rhs: T) -> Self::Output {
+                ($crate::VectorN::from(self) $op rhs).into()
             }
         }
-        impl<T> AddAssign for $t
+        impl<T> $trait<T> for &$t
         where
-            T: Copy + Add<Output = T>,
+            T: Copy + $trait<Output = T>,
         {
-            fn add_assign(&mut self, rhs: Self) {
-                for i in 0..Self::ELEM_COUNT {
-                    self[i] = self[i] + rhs[i];
-                }
+            type Output = $t;
+
+            fn $fn(self, rhs: T) -> Self::Output {
+                *self $op rhs
             }
         }
-        impl<T> Add<T> for $t
+        impl<T> $assign_trait for $t
         where
-            T: Copy + Add<Output = T>,
+            T: Copy + $trait<Output = T>,
         {
-            type Output = $t;
-
-            fn add(mut self, rhs: T) -> Self::Output {
-                for i in 0..Self::ELEM_COUNT {
-                    self[i] = self[i] + rhs;
-                }
-                return self;
+            fn $assign_fn(&mut self, rhs: Self) {
+                *self = *self $op rhs;
             }
         }
-        impl<T> AddAssign<T> for $t
+        impl<T> $assign_trait<T> for $t
         where
-            T: Copy + Add<Output = T>,
+            T: Copy + $trait<Output = T>,
         {
-            fn add_assign(&mut self, rhs: T) {
-                for i in 0..Self::ELEM_COUNT {
-                    self[i] = self[i] + rhs;
-                }
+            fn $assign_fn(&mut self, rhs: T) {
+                *self = *self $op rhs;
             }
         }
     };
 }
 
+// Kept for backwards compatibility, setup_vector_operator! replaced it
+#[deprecated(note = "use setup_vector_operator! instead")]
 #[macro_export]
-macro_rules! setup_sub {
+macro_rules! setup_add {
     ($t:ty, $a:tt) => {
-        impl<T> Sub for $t
-        where
-            T: Copy + Sub<Output = T>,
-        {
-            type Output = $t;
-
-            fn sub(mut self, rhs: Self) -> Self::Output {
-                for i in 0..Self::ELEM_COUNT {
-                    self[i] = self[i] - rhs[i];
-                }
-                return self;
-            }
-        }
-        impl<T> Sub for &$t
-        where
-            T: Copy + Sub<Output = T>,
-        {
-            type Output = $t;
-
-            fn sub(self, rhs: Self) -> Self::Output {
-                let mut me = *self;
-                let rhs = *rhs;
-                for i in 0..Self::ELEM_COUNT {
-                    me[i] = me[i] - rhs[i];
-                }
-                return me;
-            }
-        }
-        impl<T> Sub<T> for &$t
-        where
-            T: Copy + Sub<Output = T>,
-        {
-            type Output = $t;
-
-            fn sub(self, rhs: T) -> Self::Output {
-                let mut me = *self;
-                let rhs = rhs;
-                for i in 0..Self::ELEM_COUNT {
-                    me[i] = me[i] - rhs;
-                }
-                return me;
-            }
-        }
-        impl<T> SubAssign for $t
-        where
-            T: Copy + Sub<Output = T>,
-        {
-            fn sub_assign(&mut self, rhs: Self) {
-                for i in 0..Self::ELEM_COUNT {
-                    self[i] = self[i] - rhs[i];
-                }
-            }
-        }
-        impl<T> Sub<T> for $t
-        where
-            T: Copy + Sub<Output = T>,
-        {
-            type Output = $t;
+        $crate::setup_vector_operator!($t, Add, add, AddAssign, add_assign, +);
+    };
+}
 
-            fn sub(mut self, rhs: T) -> Self::Output {
-                for i in 0..Self::ELEM_COUNT {
-                    self[i] = self[i] - rhs;
-                }
-                return self;
-            }
-        }
-        impl<T> SubAssign<T> for $t
-        where
-            T: Copy + Sub<Output = T>,
-        {
-            fn sub_assign(&mut self, rhs: T) {
-                for i in 0..Self::ELEM_COUNT {
-                    self[i] = self[i] - rhs;
-                }
-            }
-        }
+// Kept for backwards compatibility, setup_vector_operator! replaced it
+#[deprecated(note = "use setup_vector_operator! instead")]
+#[macro_export]
+macro_rules! setup_sub {
+    ($t:ty, $a:tt) => {
+        $crate::setup_vector_operator!($t, Sub, sub, SubAssign, sub_assign, -);
     };
 }
 
+// Kept for backwards compatibility, setup_vector_operator! replaced it
+#[deprecated(note = "use setup_vector_operator! instead")]
 #[macro_export]
 macro_rules! setup_mul {
     ($t:ty, $a:tt) => {
-        impl<T> Mul for $t
-        where
-            T: Copy + Mul<Output = T>,
-        {
-            type Output = $t;
-
-            fn mul(mut self, rhs: Self) -> Self::Output {
-                for i in 0..Self::ELEM_COUNT {
-                    self[i] = self[i] * rhs[i];
-                }
-                return self;
-            }
-        }
-        impl<T> Mul for &$t
-        where
-            T: Copy + Mul<Output = T>,
-        {
-            type Output = $t;
-
-            fn mul(self, rhs: Self) -> Self::Output {
-                let mut me = *self;
-                let rhs = *rhs;
-                for i in 0..Self::ELEM_COUNT {
-                    me[i] = me[i] * rhs[i];
-                }
-                return me;
-            }
-        }
-        impl<T> Mul<T> for &$t
-        where
-            T: Copy + Mul<Output = T>,
-        {
-            type Output = $t;
-
-            fn mul(self, rhs: T) -> Self::Output {
-                let mut me = *self;
-                let rhs = rhs;
-                for i in 0..Self::ELEM_COUNT {
-                    me[i] = me[i] * rhs;
-                }
-                return me;
-            }
-        }
-        impl<T> MulAssign for $t
-        where
-            T: Copy + Mul<Output = T>,
-        {
-            fn mul_assign(&mut self, rhs: Self) {
-                for i in 0..Self::ELEM_COUNT {
-                    self[i] = self[i] * rhs[i];
-                }
-            }
-        }
-        impl<T> Mul<T> for $t
-        where
-            T: Copy + Mul<Output = T>,
-        {
-            type Output = $t;
-
-            fn mul(mut self, rhs: T) -> Self::Output {
-                for i in 0..Self::ELEM_COUNT {
-                    self[i] = self[i] * rhs;
-                }
-                return self;
-            }
-        }
-        impl<T> MulAssign<T> for $t
-        where
-            T: Copy + Mul<Output = T>,
-        {
-            fn mul_assign(&mut self, rhs: T) {
-                for i in 0..Self::ELEM_COUNT {
-                    self[i] = self[i] * rhs;
-                }
-            }
-        }
+        $crate::setup_vector_operator!($t, Mul, mul, MulAssign, mul_assign, *);
     };
 }
 
+// Kept for backwards compatibility, setup_vector_operator! replaced it
+#[deprecated(note = "use setup_vector_operator! instead")]
 #[macro_export]
 macro_rules! setup_div {
     ($t:ty, $a:tt) => {
-        impl<T> Div for $t
-        where
-            T: Copy + Div<Output = T>,
-        {
-            type Output = $t;
-
-            fn div(mut self, rhs: Self) -> Self::Output {
-                for i in 0..Self::ELEM_COUNT {
-                    self[i] = self[i] / rhs[i];
-                }
-                return self;
-            }
-        }
-        impl<T> Div for &$t
-        where
-            T: Copy + Div<Output = T>,
-        {
-            type Output = $t;
-
-            fn div(self, rhs: Self) -> Self::Output {
-                let mut me = *self;
-                let rhs = *rhs;
-                for i in 0..Self::ELEM_COUNT {
-                    me[i] = me[i] / rhs[i];
-                }
-                return me;
-            }
-        }
-        impl<T> Div<T> for &$t
-        where
-            T: Copy + Div<Output = T>,
-        {
-            type Output = $t;
-
-            fn div(self, rhs: T) -> Self::Output {
-                let mut me = *self;
-                let rhs = rhs;
-                for i in 0..Self::ELEM_COUNT {
-                    me[i] = me[i] / rhs;
-                }
-                return me;
-            }
-        }
-        impl<T> DivAssign for $t
-        where
-            T: Copy + Div<Output = T>,
-        {
-            fn div_assign(&mut self, rhs: Self) {
-                for i in 0..Self::ELEM_COUNT {
-                    self[i] = self[i] / rhs[i];
-                }
-            }
-        }
-        impl<T> Div<T> for $t
-        where
-            T: Copy + Div<Output = T>,
-        {
-            type Output = $t;
-
-            fn div(mut self, rhs: T) -> Self::Output {
-                for i in 0..Self::ELEM_COUNT {
-                    self[i] = self[i] / rhs;
-                }
-                return self;
-            }
-        }
-        impl<T> DivAssign<T> for $t
-        where
-            T: Copy + Div<Output = T>,
-        {
-            fn div_assign(&mut self, rhs: T) {
-                for i in 0..Self::ELEM_COUNT {
-                    self[i] = self[i] / rhs;
-                }
-            }
-        }
+        $crate::setup_vector_operator!($t, Div, div, DivAssign, div_assign, /);
     };
 }
 
 #[macro_export]
 macro_rules! setup_neg {
-    ($t:ty, $a:tt) => {
+    ($t:ty) => {
         impl<T> Neg for $t
         where
-            T: SupportedValue + Neg<Output = T> + Copy,
+            T: Copy + Neg<Output = T>,
         {
             type Output = $t;
 
-            fn neg(mut self) -> Self::Output {
-                for i in 0..Self::ELEM_COUNT {
-                    self[i] = -self[i];
-                }
-                return self;
+            fn neg(self) -> Self::Output {
+                (-$crate::VectorN::from(self)).into()
             }
         }
         impl<T> Neg for &$t
         where
-            T: SupportedValue + Neg<Output = T> + Copy,
+            T: Copy + Neg<Output = T>,
         {
             type Output = $t;
 
             fn neg(self) -> Self::Output {
-                let mut output = <$t>::ZERO;
-                for i in 0..(<$t>::ELEM_COUNT) {
-                    output[i] = -self[i];
-                }
-                return output;
+                -*self
             }
         }
     };
+    // The old form, with an unused element type argument
+    ($t:ty, $a:tt) => {
+        $crate::setup_neg!($t);
+    };
 }
 
 #[macro_export]
 macro_rules! setup_any_vector_operations {
+    ($t:ty) => {
+        impl<T> $t
+        where
+            T: Copy + PartialOrd,
+        {
+            // Get the min value between two vectors
+            pub fn min(self, other: Self) -> Self {
+                $crate::VectorN::from(self).min(other.into()).into()
+            }
+            // Get the max value between two vectors
+            pub fn max(self, other: Self) -> Self {
+                $crate::VectorN::from(self).max(other.into()).into()
+            }
+            // Clamp the current value between some bounds and return it
+            pub fn clamp(self, min: Self, max: Self) -> Self {
+                self.min(max).max(min)
+            }
+        }
+    };
+    // The old form, which implements the operations for a single element type
     ($t:ty, $a:tt, $f: ty) => {
         impl $t {
-            // Get the min value between two vec3s
+            // Get the min value between two vectors
             pub fn min(self, other: Self) -> Self {
-                let mut min = <$t>::ZERO;
-                for i in 0..Self::ELEM_COUNT {
-                    min[i] = self[i].min(other[i]);
-                }
-                return min;
+                $crate::VectorN::from(self).min(other.into()).into()
             }
-            // Get the max value between two vec3s
+            // Get the max value between two vectors
             pub fn max(self, other: Self) -> Self {
-                let mut min = <$t>::ZERO;
-                for i in 0..Self::ELEM_COUNT {
-                    min[i] = self[i].max(other[i]);
-                }
-                return min;
+                $crate::VectorN::from(self).max(other.into()).into()
             }
             // Clamp the current value between some bounds and return it
             pub fn clamp(self, min: Self, max: Self) -> Self {
-                return self.min(max).max(min);
+                self.min(max).max(min)
             }
         }
     };
@@ -440,76 +220,80 @@ macro_rules! setup_any_vector_operations {
 
 #[macro_export]
 macro_rules! setup_floating_vector_operations {
-    ($t:ty, $a:tt, $f: ty) => {
-        // Setup the shared vector operations
+    ($t:ty, $f: ty) => {
+        $crate::setup_floating_vector_operations!(@shared $t, $f);
         impl $t {
-            // Get the distance from another vector
-            pub fn distance(self, other: Self) -> $f {
-                let test: $t = self - other;
-                return test.length();
-            }
             // Get the length square of the current vector (Saves us a sqrt operation)
             pub fn length_sqrt(self) -> $f {
-                let mut len: $f = 0.0;
-                for i in 0..Self::ELEM_COUNT {
-                    len += self[i] * self[i];
-                }
-                return len;
+                $crate::VectorN::from(self).length_sqrt()
             }
-            // Get the length of the current vector
-            pub fn length(self) -> $f {
-                return self.length_sqrt().sqrt();
+            // Normalize the current vector
+            pub fn normalize(&mut self) {
+                *self = self.normalized();
+            }
+            // Get the normalized value of the current vector without updating it
+            pub fn normalized(self) -> Self {
+                $crate::VectorN::from(self).normalized().into()
+            }
+            // Get the dot product between two vectors
+            pub fn dot(self, other: Self) -> $f {
+                $crate::VectorN::from(self).dot(other.into())
+            }
+        }
+    };
+    // Same as above, but the dot product and normalization use the SIMD kernels
+    ($t:ty, $f: ty, simd) => {
+        $crate::setup_floating_vector_operations!(@shared $t, $f);
+        impl $t {
+            // Get the length square of the current vector (Saves us a sqrt operation)
+            pub fn length_sqrt(self) -> $f {
+                $crate::simd::dot(&self, &self)
             }
             // Normalize the current vector
             pub fn normalize(&mut self) {
-                let len = self.length();
-                for i in 0..Self::ELEM_COUNT {
-                    self[i] /= len;
-                }
+                *self = $crate::simd::normalized(self);
             }
             // Get the normalized value of the current vector without updating it
             pub fn normalized(self) -> Self {
-                let len = self.length();
-                let mut output: Self = Self::ZERO;
-                for i in 0..Self::ELEM_COUNT {
-                    output[i] = self[i] / len;
-                }
-                return output;
+                $crate::simd::normalized(&self)
             }
             // Get the dot product between two vectors
             pub fn dot(self, other: Self) -> $f {
-                let mut dot: $f = 0.0;
-                for i in 0..Self::ELEM_COUNT {
-                    dot += self[i] * other[i];
-                }
-                return dot;
+                $crate::simd::dot(&self, &other)
+            }
+        }
+    };
+    // The old form, with an unused element type argument
+    ($t:ty, $a:tt, $f: ty) => {
+        $crate::setup_floating_vector_operations!($t, $f);
+    };
+    (@shared $t:ty, $f: ty) => {
+        // Setup the shared vector operations
+        impl $t {
+            // Get the distance from another vector
+            pub fn distance(self, other: Self) -> $f {
+                (self - other).length()
+            }
+            // Get the length of the current vector
+            pub fn length(self) -> $f {
+                self.length_sqrt().sqrt()
             }
             // Floor
-            pub fn floor(mut self) -> Self {
-                for i in 0..Self::ELEM_COUNT {
-                    self[i] = self[i].floor();
-                }
-                self
+            pub fn floor(self) -> Self {
+                $crate::VectorN::from(self).floor().into()
             }
             // Round
-            pub fn round(mut self) -> Self {
-                for i in 0..Self::ELEM_COUNT {
-                    self[i] = self[i].round();
-                }
-                self
+            pub fn round(self) -> Self {
+                $crate::VectorN::from(self).round().into()
+            }
+            // Ceil
+            pub fn ceil(self) -> Self {
+                $crate::VectorN::from(self).ceil().into()
             }
-            // Ceil  
-            pub fn ceil(mut self) -> Self {
-                for i in 0..Self::ELEM_COUNT {
-                    self[i] = self[i].ceil();
-                }
-                self
-            }        
             //https://limnu.com/sketch-lerp-unlerp-remap/
             // Lerp between two values using T
             pub fn lerp(self, other: Self, t: $f) -> Self {
-                let output = (self + ((other - self) * t));
-                return output;
+                self + (other - self) * t
             }
         }
     };
@@ -517,135 +301,78 @@ macro_rules! setup_floating_vector_operations {
 // Element wise comparison
 #[macro_export]
 macro_rules! impl_elem_wise_comparison {
-    ($t:ty, $a:tt, $out:ty) => {
+    ($t:ty, $out:ty) => {
         // Element wise comparison
         impl<T> $t
         where
-            T: Copy + PartialEq + PartialOrd,
+            T: Copy + PartialOrd,
         {
             // Equals
             pub fn elem_eq(&self, other: &Self) -> $out {
-                let mut out: $out = <$out>::ZERO;
-                for i in 0..Self::ELEM_COUNT {
-                    out[i] = self[i] == other[i];
-                }
-                out
+                $crate::VectorN::from(*self).elem_eq(&(*other).into()).into()
             }
             // Greater then
             pub fn elem_gt(&self, other: &Self) -> $out {
-                let mut out: $out = <$out>::ZERO;
-                for i in 0..Self::ELEM_COUNT {
-                    out[i] = self[i] > other[i];
-                }
-                out
+                $crate::VectorN::from(*self).elem_gt(&(*other).into()).into()
             }
             // Less than
             pub fn elem_lt(&self, other: &Self) -> $out {
-                let mut out: $out = <$out>::ZERO;
-                for i in 0..Self::ELEM_COUNT {
-                    out[i] = self[i] < other[i];
-                }
-                out
+                $crate::VectorN::from(*self).elem_lt(&(*other).into()).into()
             }
             // Greater than or equals
             pub fn elem_gte(&self, other: &Self) -> $out {
-                let mut out: $out = <$out>::ZERO;
-                for i in 0..Self::ELEM_COUNT {
-                    out[i] = self[i] >= other[i];
-                }
-                out
+                $crate::VectorN::from(*self).elem_gte(&(*other).into()).into()
             }
             // Less than or equals
             pub fn elem_lte(&self, other: &Self) -> $out {
-                let mut out: $out = <$out>::ZERO;
-                for i in 0..Self::ELEM_COUNT {
-                    out[i] = self[i] <= other[i];
-                }
-                out
+                $crate::VectorN::from(*self).elem_lte(&(*other).into()).into()
             }
         }
         impl $out {
             // Return true if all the elements are true
             pub fn all(&self) -> bool {
-                let mut out: bool = true;
-                for i in 0..Self::ELEM_COUNT {
-                    out &= self[i];
-                }
-                out
+                $crate::VectorN::from(*self).all()
             }
             // Return true if one or more elements are true
             pub fn any(&self) -> bool {
-                let mut out: bool = false;
-                for i in 0..Self::ELEM_COUNT {
-                    out |= self[i];
-                }
-                out
+                $crate::VectorN::from(*self).any()
             }
-        }
-        impl $out {
             // Select between two vectors using the elements of the current bool vector
-            pub fn select<T: Clone + Copy>(&self, first: &$t, second: &$t) -> $t {
-                let mut output_vector: $t = (*first).clone();
-                for i in 0..Self::ELEM_COUNT {
-                    output_vector[i] = if self[i] { first[i] } else { second[i] }
-                }
-                return output_vector;
-            }
-        }
-        impl<T> BitAnd for $t
-        where
-            T: SupportedValue + BitAnd + BitAndAssign,
-        {
-            type Output = $t;
-            // Element wise and
-            fn bitand(self, rhs: Self) -> Self::Output {
-                let mut out: $t = self;
-                for i in 0..Self::ELEM_COUNT {
-                    out[i] &= rhs[i];
-                }
-                return out;
-            }
-        }
-        impl<T> BitOr for $t
-        where
-            T: SupportedValue + BitOr + BitOrAssign,
-        {
-            type Output = $t;
-            // Element wise or
-            fn bitor(self, rhs: Self) -> Self::Output {
-                let mut out: $t = self;
-                for i in 0..Self::ELEM_COUNT {
-                    out[i] |= rhs[i];
-                }
-                return out;
+            pub fn select<T: Copy>(&self, first: &$t, second: &$t) -> $t {
+                $crate::VectorN::from(*self).select(&(*first).into(), &(*second).into()).into()
             }
         }
-        impl<T> BitXor for $t
+        $crate::setup_vector_bit_operator!($t, BitAnd, bitand, &);
+        $crate::setup_vector_bit_operator!($t, BitOr, bitor, |);
+        $crate::setup_vector_bit_operator!($t, BitXor, bitxor, ^);
+        impl<T> Not for $t
         where
-            T: SupportedValue + BitXor + BitXorAssign,
+            T: Copy + Not<Output = T>,
         {
             type Output = $t;
-            // Element wise xor
-            fn bitxor(self, rhs: Self) -> Self::Output {
-                let mut out: $t = self;
-                for i in 0..Self::ELEM_COUNT {
-                    out[i] ^= rhs[i];
-                }
-                return out;
+            // Element wise not
+            fn not(self) -> Self::Output {
+                (!$crate::VectorN::from(self)).into()
             }
         }
-        impl<T> Not for $t
+    };
+    // The old form, with an unused element type argument
+    ($t:ty, $a:tt, $out:ty) => {
+        $crate::impl_elem_wise_comparison!($t, $out);
+    };
+}
+// Element wise bit operators for Vector2, Vector3 and Vector4, which go through VectorN
+#[macro_export]
+macro_rules! setup_vector_bit_operator {
+    ($t:ty, $trait:ident, $fn:ident, $op:tt) => {
+        impl<T> $trait for $t
         where
-            T: SupportedValue + Not<Output = T>,
+            T: Copy + $trait<Output = T>,
         {
             type Output = $t;
-            // Element wise not
-            fn not(self) -> Self::Output {
-                let mut out: $t = self;
-                for i in 0..Self::ELEM_COUNT {
-                    out[i] = !self[i];
-                }
-                return out;
+            // Element wise operator
+            fn $fn(self, rhs: Self) -> Self::Output {
+                ($crate::VectorN::from(self) $op $crate::VectorN::from(rhs)).into()
             }
         }
     };
@@ -666,24 +393,96 @@ macro_rules! impl_eq_hash {
 #[macro_export]
 macro_rules! impl_matrix {
     ($t: ty, $f: ty) => {
-        // Creation code for the matrix
-        #[allow(dead_code)]
+        $crate::impl_matrix!(@shared $t, $f);
         impl $t {
-            // Create a matrix from 4 vector4s
-            pub fn new(vec1: Vector4<$f>, vec2: Vector4<$f>, vec3: Vector4<$f>, vec4: Vector4<$f>) -> Self {
-                Matrix4x4 { data: [vec1, vec2, vec3, vec4] }
+            // Multiply a matrix by this matrix
+            pub fn mul_mat4x4(&self, other: Matrix4x4<$f>) -> Self {
+                // Collumn major, so each output collumn is this matrix transforming the other collumn
+                Matrix4x4 { data: other.data.map(|collumn| self.mul_vector(&collumn)) }
             }
-            // Create a perspective projection matrix
-            // Bonk https://gamedev.stackexchange.com/questions/120338/what-does-a-perspective-projection-matrix-look-like-in-opengl
-            pub fn from_perspective(near: $f, far: $f, aspect: $f, fov: $f) -> Self {
-                // Math
-                let first = 1.0 / (aspect * (fov / 2.0).tan());
-                let second = 1.0 / (fov / 2.0).tan();
-                // The output
-                let mut matrix: Self = Self::IDENTITY;
-                // Right now it is using row major but I will switch it to collumn major later
-                // This is row major
-                *matrix.get_vec_mut(0) = Vector4::new(first, 0.0, 0.0, 0.0);
+            // Get the adjugate (transposed cofactor matrix) of this matrix
+            // https://stackoverflow.com/questions/1148309/inverting-a-4x4-matrix/44446912#44446912
+            fn adjugate(&self) -> Self {
+                let m = *self;
+                let mut inv = Self::default();
+
+                inv[0] = m[5] * m[10] * m[15] - m[5] * m[11] * m[14] - m[9] * m[6] * m[15] + m[9] * m[7] * m[14] + m[13] * m[6] * m[11] - m[13] * m[7] * m[10];
+
+                inv[4] = -m[4] * m[10] * m[15] + m[4] * m[11] * m[14] + m[8] * m[6] * m[15] - m[8] * m[7] * m[14] - m[12] * m[6] * m[11] + m[12] * m[7] * m[10];
+
+                inv[8] = m[4] * m[9] * m[15] - m[4] * m[11] * m[13] - m[8] * m[5] * m[15] + m[8] * m[7] * m[13] + m[12] * m[5] * m[11] - m[12] * m[7] * m[9];
+
+                inv[12] = -m[4] * m[9] * m[14] + m[4] * m[10] * m[13] + m[8] * m[5] * m[14] - m[8] * m[6] * m[13] - m[12] * m[5] * m[10] + m[12] * m[6] * m[9];
+
+                inv[1] = -m[1] * m[10] * m[15] + m[1] * m[11] * m[14] + m[9] * m[2] * m[15] - m[9] * m[3] * m[14] - m[13] * m[2] * m[11] + m[13] * m[3] * m[10];
+
+                inv[5] = m[0] * m[10] * m[15] - m[0] * m[11] * m[14] - m[8] * m[2] * m[15] + m[8] * m[3] * m[14] + m[12] * m[2] * m[11] - m[12] * m[3] * m[10];
+
+                inv[9] = -m[0] * m[9] * m[15] + m[0] * m[11] * m[13] + m[8] * m[1] * m[15] - m[8] * m[3] * m[13] - m[12] * m[1] * m[11] + m[12] * m[3] * m[9];
+
+                inv[13] = m[0] * m[9] * m[14] - m[0] * m[10] * m[13] - m[8] * m[1] * m[14] + m[8] * m[2] * m[13] + m[12] * m[1] * m[10] - m[12] * m[2] * m[9];
+
+                inv[2] = m[1] * m[6] * m[15] - m[1] * m[7] * m[14] - m[5] * m[2] * m[15] + m[5] * m[3] * m[14] + m[13] * m[2] * m[7] - m[13] * m[3] * m[6];
+
+                inv[6] = -m[0] * m[6] * m[15] + m[0] * m[7] * m[14] + m[4] * m[2] * m[15] - m[4] * m[3] * m[14] - m[12] * m[2] * m[7] + m[12] * m[3] * m[6];
+
+                inv[10] = m[0] * m[5] * m[15] - m[0] * m[7] * m[13] - m[4] * m[1] * m[15] + m[4] * m[3] * m[13] + m[12] * m[1] * m[7] - m[12] * m[3] * m[5];
+
+                inv[14] = -m[0] * m[5] * m[14] + m[0] * m[6] * m[13] + m[4] * m[1] * m[14] - m[4] * m[2] * m[13] - m[12] * m[1] * m[6] + m[12] * m[2] * m[5];
+
+                inv[3] = -m[1] * m[6] * m[11] + m[1] * m[7] * m[10] + m[5] * m[2] * m[11] - m[5] * m[3] * m[10] - m[9] * m[2] * m[7] + m[9] * m[3] * m[6];
+
+                inv[7] = m[0] * m[6] * m[11] - m[0] * m[7] * m[10] - m[4] * m[2] * m[11] + m[4] * m[3] * m[10] + m[8] * m[2] * m[7] - m[8] * m[3] * m[6];
+
+                inv[11] = -m[0] * m[5] * m[11] + m[0] * m[7] * m[9] + m[4] * m[1] * m[11] - m[4] * m[3] * m[9] - m[8] * m[1] * m[7] + m[8] * m[3] * m[5];
+
+                inv[15] = m[0] * m[5] * m[10] - m[0] * m[6] * m[9] - m[4] * m[1] * m[10] + m[4] * m[2] * m[9] + m[8] * m[1] * m[6] - m[8] * m[2] * m[5];
+                inv
+            }
+            // Transform a 4D vector by the matrix
+            pub fn mul_vector(&self, vector: &Vector4<$f>) -> Vector4<$f> {
+                // Multiply the vector by this matrix (collumn major, so each collumn gets scaled by one element)
+                *self.get_vec(0) * vector.x + *self.get_vec(1) * vector.y + *self.get_vec(2) * vector.z + *self.get_vec(3) * vector.w
+            }
+        }
+    };
+    // Same as above, but multiplication and the adjugate (used for the inverse) use the SIMD kernels
+    ($t: ty, $f: ty, simd) => {
+        $crate::impl_matrix!(@shared $t, $f);
+        impl $t {
+            // Multiply a matrix by this matrix
+            pub fn mul_mat4x4(&self, other: Matrix4x4<$f>) -> Self {
+                $crate::simd::mul_mat4x4(self, &other)
+            }
+            // Get the adjugate (transposed cofactor matrix) of this matrix
+            fn adjugate(&self) -> Self {
+                $crate::simd::adjugate(self)
+            }
+            // Transform a 4D vector by the matrix
+            pub fn mul_vector(&self, vector: &Vector4<$f>) -> Vector4<$f> {
+                $crate::simd::mul_vector(self, vector)
+            }
+        }
+    };
+    (@shared $t: ty, $f: ty) => {
+        // Creation code for the matrix
+        #[allow(dead_code)]
+        impl $t {
+            // Create a matrix from 4 vector4s
+            pub fn new(vec1: Vector4<$f>, vec2: Vector4<$f>, vec3: Vector4<$f>, vec4: Vector4<$f>) -> Self {
+                Matrix4x4 { data: [vec1, vec2, vec3, vec4] }
+            }
+            // Create a perspective projection matrix
+            // Bonk https://gamedev.stackexchange.com/questions/120338/what-does-a-perspective-projection-matrix-look-like-in-opengl
+            pub fn from_perspective(near: $f, far: $f, aspect: $f, fov: $f) -> Self {
+                // Math
+                let first = 1.0 / (aspect * (fov / 2.0).tan());
+                let second = 1.0 / (fov / 2.0).tan();
+                // The output
+                let mut matrix: Self = Self::IDENTITY;
+                // Right now it is using row major but I will switch it to collumn major later
+                // This is row major
+                *matrix.get_vec_mut(0) = Vector4::new(first, 0.0, 0.0, 0.0);
                 *matrix.get_vec_mut(1) = Vector4::new(0.0, second, 0.0, 0.0);
                 *matrix.get_vec_mut(2) = Vector4::new(0.0, 0.0, -((far + near) / (far - near)), -((2.0 * far * near) / (far - near)));
                 *matrix.get_vec_mut(3) = -Vector4::Z;
@@ -719,7 +518,7 @@ macro_rules! impl_matrix {
             pub fn look_at(eye: &Vector3<$f>, up: &Vector3<$f>, target: &Vector3<$f>) -> Self {
                 // The output
                 let zaxis: Vector3<$f> = (*target - *eye).normalized();
-                let xaxis: Vector3<$f> = zaxis.cross(*up);
+                let xaxis: Vector3<$f> = zaxis.cross(*up).normalized();
                 let yaxis: Vector3<$f> = xaxis.cross(zaxis);
 
                 let zaxis = -zaxis;
@@ -740,113 +539,409 @@ macro_rules! impl_matrix {
             }
             // Create a rotation matrix
             pub fn from_quaternion(quat: &Quaternion<$f>) -> Self {
-                let qx = quat[0];
-                let qy = quat[1];
-                let qz = quat[2];
-                let qw = quat[3];
-                let vec1 = Vector4::<$f>::new(1.0 - 2.0 * qy * qy - 2.0 * qz * qz, 2.0 * qx * qy - 2.0 * qz * qw, 2.0 * qx * qz + 2.0 * qy * qw, 0.0);
-                let vec2 = Vector4::<$f>::new(2.0 * qx * qy + 2.0 * qz * qw, 1.0 - 2.0 * qx * qx - 2.0 * qz * qz, 2.0 * qy * qz - 2.0 * qx * qw, 0.0);
-                let vec3 = Vector4::<$f>::new(2.0 * qx * qz - 2.0 * qy * qw, 2.0 * qy * qz + 2.0 * qx * qw, 1.0 - 2.0 * qx * qx - 2.0 * qy * qy, 0.0);
-                let vec4 = Vector4::<$f>::W;
-                Matrix4x4::<$f>::new(vec1, vec2, vec3, vec4)
+                Matrix3x3::<$f>::from_quaternion(quat).into()
             }
             // Create a scale matrix
             pub fn from_scale(scale: Vector3<$f>) -> Self {
                 // Too good bro
                 Matrix4x4::<$f>::new(Vector4::X * scale.x, Vector4::Y * scale.y, Vector4::Z * scale.z, Vector4::W)
             }
-            // Multiply a matrix by this matrix
-            pub fn mul_mat4x4(&self, other: Matrix4x4<$f>) -> Self {
-                let mut output: Self = Self::IDENTITY;
-                // Get the A vectors
-                let mut a_vectors: [Vector4<$f>; 4] = [Vector4::<$f>::ZERO; 4];
-                for y in 0..4 {
-                    a_vectors[y][0] = self.get_vec(0)[y];
-                    a_vectors[y][1] = self.get_vec(1)[y];
-                    a_vectors[y][2] = self.get_vec(2)[y];
-                    a_vectors[y][3] = self.get_vec(3)[y];
-                }
-                // Get the dot product
-                for y in 0..4 {
-                    for x in 0..4 {
-                        // Collumn major
-                        // Get A
-                        let a: Vector4<$f> = a_vectors[y];
-                        let b = *other.get_vec(x);
-                        let h = &mut output.get_vec_mut(x)[y];
-                        *h = a.dot(b);
-                    }
+            // Create a transform matrix out of a translation, rotation and scale, just like T * R * S but without the multiplications
+            pub fn from_trs(translation: Vector3<$f>, rotation: &Quaternion<$f>, scale: Vector3<$f>) -> Self {
+                let rotation = Matrix3x3::<$f>::from_quaternion(rotation);
+                let x = rotation.get_vec(0) * scale.x;
+                let y = rotation.get_vec(1) * scale.y;
+                let z = rotation.get_vec(2) * scale.z;
+                Matrix4x4::<$f>::new(
+                    Vector4::new(x.x, x.y, x.z, 0.0),
+                    Vector4::new(y.x, y.y, y.z, 0.0),
+                    Vector4::new(z.x, z.y, z.z, 0.0),
+                    Vector4::new(translation.x, translation.y, translation.z, 1.0),
+                )
+            }
+            // Decompose this matrix into its translation, rotation and scale
+            // This returns None if the matrix is not affine, if it contains shear, or if one of the axii got scaled down to 0
+            pub fn decompose(&self) -> Option<(Vector3<$f>, Quaternion<$f>, Vector3<$f>)> {
+                // Scaled by the precision of the type, so f64 matrices get checked more strictly than f32 ones
+                let tolerance: $f = <$f>::EPSILON * 1024.0;
+                // The last row must be [0, 0, 0, 1]
+                let row = Vector4::new(self.get_vec(0).w, self.get_vec(1).w, self.get_vec(2).w, self.get_vec(3).w);
+                if (row - Vector4::W).length() > tolerance {
+                    return None;
                 }
-                output
+                let translation = self.get_vec(3).get3([0, 1, 2]);
+                let x = self.get_vec(0).get3([0, 1, 2]);
+                let y = self.get_vec(1).get3([0, 1, 2]);
+                let z = self.get_vec(2).get3([0, 1, 2]);
+                let mut scale = Vector3::new(x.length(), y.length(), z.length());
+                // Relative to the biggest axis, so uniformly tiny matrices still decompose
+                let largest = scale.x.max(scale.y).max(scale.z);
+                if scale.x <= largest * tolerance || scale.y <= largest * tolerance || scale.z <= largest * tolerance {
+                    return None;
+                }
+                // A negative determinant means that the matrix got mirrored, so we put that on the X scale
+                if x.dot(y.cross(z)) < 0.0 {
+                    scale.x = -scale.x;
+                }
+                let x = x / scale.x;
+                let y = y / scale.y;
+                let z = z / scale.z;
+                // The axii must be perpendicular to each other
+                if x.dot(y).abs() > tolerance || x.dot(z).abs() > tolerance || y.dot(z).abs() > tolerance {
+                    return None;
+                }
+                let rotation = Quaternion::<$f>::from_rotation_matrix(Matrix3x3::<$f>::new(x, y, z));
+                Some((translation, rotation, scale))
             }
-            // Return the inverse of this matrix
-            // https://stackoverflow.com/questions/1148309/inverting-a-4x4-matrix/44446912#44446912
-            pub fn inverse(&self, inverse: &mut Self) -> bool {
+            // Get the determinant of this matrix
+            pub fn determinant(&self) -> $f {
                 let m = *self;
-                let mut inv = Self::default();
-
-                inv[0] = m[5] * m[10] * m[15] - m[5] * m[11] * m[14] - m[9] * m[6] * m[15] + m[9] * m[7] * m[14] + m[13] * m[6] * m[11] - m[13] * m[7] * m[10];
-
-                inv[4] = -m[4] * m[10] * m[15] + m[4] * m[11] * m[14] + m[8] * m[6] * m[15] - m[8] * m[7] * m[14] - m[12] * m[6] * m[11] + m[12] * m[7] * m[10];
+                let inv = self.adjugate();
+                m[0] * inv[0] + m[1] * inv[4] + m[2] * inv[8] + m[3] * inv[12]
+            }
+            // Return the inverse of this matrix, or an error containing the determinant if the matrix is singular
+            pub fn try_inverse(&self) -> Result<Self, SingularMatrix<$f>> {
+                let m = *self;
+                let inv = self.adjugate();
+                let det = m[0] * inv[0] + m[1] * inv[4] + m[2] * inv[8] + m[3] * inv[12];
+                // Scale aware check, since the determinant can never be bigger than the product of the collumn lengths
+                let bound: $f = (0..4).map(|i| self.get_vec(i).length()).product();
+                if det.abs() <= bound * <$f>::EPSILON {
+                    return Err(SingularMatrix { determinant: det });
+                }
 
-                inv[8] = m[4] * m[9] * m[15] - m[4] * m[11] * m[13] - m[8] * m[5] * m[15] + m[8] * m[7] * m[13] + m[12] * m[5] * m[11] - m[12] * m[7] * m[9];
+                let mut output = inv;
+                for i in 0..16 {
+                    output[i] = inv[i] * (1.0 / det);
+                }
+                Ok(output)
+            }
+            // Return the inverse of this matrix, or false if the matrix is singular
+            pub fn inverse(&self, inverse: &mut Self) -> bool {
+                match self.try_inverse() {
+                    Ok(output) => {
+                        *inverse = output;
+                        true
+                    }
+                    Err(_) => false,
+                }
+            }
+            // Inversed, which returns the identity matrix if the matrix is singular. Use try_inverse to catch that case
+            pub fn inversed(&self) -> Self {
+                self.try_inverse().unwrap_or(Self::IDENTITY)
+            }
+            // Return the inverse of an affine matrix (last row being [0, 0, 0, 1]), which is cheaper than a full inverse
+            pub fn affine_inverse(&self) -> Result<Self, SingularMatrix<$f>> {
+                let inverse = Matrix3x3::<$f>::from(*self).try_inverse()?;
+                let translation = -inverse.mul_vector(&self.get_vec(3).get3([0, 1, 2]));
+                let mut output: Self = inverse.into();
+                *output.get_vec_mut(3) = Vector4::new(translation.x, translation.y, translation.z, 1.0);
+                Ok(output)
+            }
+            // Return the inverse of a rigid matrix (only rotation and translation), which is just a transpose
+            pub fn rigid_inverse(&self) -> Self {
+                let inverse = Matrix3x3::<$f>::from(*self).transposed();
+                let translation = -inverse.mul_vector(&self.get_vec(3).get3([0, 1, 2]));
+                let mut output: Self = inverse.into();
+                *output.get_vec_mut(3) = Vector4::new(translation.x, translation.y, translation.z, 1.0);
+                output
+            }
+            // Transform a 3D point by the matrix, basically create a 4D vector out of it with the W component being 1.0
+            pub fn mul_point(&self, point: &Vector3<$f>) -> Vector3<$f> {
+                self.mul_vector(&Vector4::new(point.x, point.y, point.z, 1.0)).get3([0, 1, 2])
+            }
+            // Transform a 3D point by the matrix and divide the result by its W component (for projection matrices)
+            pub fn mul_point_projective(&self, point: &Vector3<$f>) -> Vector3<$f> {
+                let output = self.mul_vector(&Vector4::new(point.x, point.y, point.z, 1.0));
+                output.get3([0, 1, 2]) / output.w
+            }
+            // Transform a lot of points at once, like mul_point
+            pub fn transform_points(&self, points: &mut [Vector3<$f>]) {
+                let [x, y, z, w] = self.data.map(|collumn| collumn.get3([0, 1, 2]));
+                $crate::batch::map_in_place(points, |point| x * point.x + y * point.y + z * point.z + w);
+            }
+            // Transform a lot of points at once, and write them into the destination slice, which must have the same length
+            pub fn transform_points_into(&self, src: &[Vector3<$f>], dst: &mut [Vector3<$f>]) {
+                let [x, y, z, w] = self.data.map(|collumn| collumn.get3([0, 1, 2]));
+                $crate::batch::map_into(src, dst, |point| x * point.x + y * point.y + z * point.z + w);
+            }
+            // Transform a lot of directions at once, ignoring the translation of the matrix
+            pub fn transform_vectors(&self, vectors: &mut [Vector3<$f>]) {
+                let [x, y, z, _] = self.data.map(|collumn| collumn.get3([0, 1, 2]));
+                $crate::batch::map_in_place(vectors, |vector| x * vector.x + y * vector.y + z * vector.z);
+            }
+            // Transform a lot of points at once, like mul_point_projective
+            pub fn transform_points_projective(&self, points: &mut [Vector3<$f>]) {
+                let matrix = *self;
+                $crate::batch::map_in_place(points, |point| matrix.mul_point_projective(&point));
+            }
+        }
 
-                inv[12] = -m[4] * m[9] * m[14] + m[4] * m[10] * m[13] + m[8] * m[5] * m[14] - m[8] * m[6] * m[13] - m[12] * m[5] * m[10] + m[12] * m[6] * m[9];
+        // Operators
+        $crate::impl_matrix_operators!($t, $f, Vector4<$f>, mul_mat4x4);
+        impl Mul<Vector3<$f>> for $t {
+            type Output = Vector3<$f>;
 
-                inv[1] = -m[1] * m[10] * m[15] + m[1] * m[11] * m[14] + m[9] * m[2] * m[15] - m[9] * m[3] * m[14] - m[13] * m[2] * m[11] + m[13] * m[3] * m[10];
+            fn mul(self, rhs: Vector3<$f>) -> Vector3<$f> {
+                self.mul_point(&rhs)
+            }
+        }
+    };
+}
 
-                inv[5] = m[0] * m[10] * m[15] - m[0] * m[11] * m[14] - m[8] * m[2] * m[15] + m[8] * m[3] * m[14] + m[12] * m[2] * m[11] - m[12] * m[3] * m[10];
+// The operators shared by every floating point matrix
+#[macro_export]
+macro_rules! impl_matrix_operators {
+    ($t: ty, $f: ty, $v: ty, $mul: ident) => {
+        // Multiply this matrix by another matrix
+        impl Mul for $t {
+            type Output = Self;
 
-                inv[9] = -m[0] * m[9] * m[15] + m[0] * m[11] * m[13] + m[8] * m[1] * m[15] - m[8] * m[3] * m[13] - m[12] * m[1] * m[11] + m[12] * m[3] * m[9];
+            fn mul(self, rhs: Self) -> Self {
+                self.$mul(rhs)
+            }
+        }
+        impl MulAssign for $t {
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = self.$mul(rhs);
+            }
+        }
+        // Transform a vector by this matrix
+        impl Mul<$v> for $t {
+            type Output = $v;
 
-                inv[13] = m[0] * m[9] * m[14] - m[0] * m[10] * m[13] - m[8] * m[1] * m[14] + m[8] * m[2] * m[13] + m[12] * m[1] * m[10] - m[12] * m[2] * m[9];
+            fn mul(self, rhs: $v) -> $v {
+                self.mul_vector(&rhs)
+            }
+        }
+        // Element wise operations
+        impl Mul<$f> for $t {
+            type Output = Self;
 
-                inv[2] = m[1] * m[6] * m[15] - m[1] * m[7] * m[14] - m[5] * m[2] * m[15] + m[5] * m[3] * m[14] + m[13] * m[2] * m[7] - m[13] * m[3] * m[6];
+            fn mul(mut self, rhs: $f) -> Self {
+                for vector in self.data.iter_mut() {
+                    *vector *= rhs;
+                }
+                self
+            }
+        }
+        impl MulAssign<$f> for $t {
+            fn mul_assign(&mut self, rhs: $f) {
+                *self = *self * rhs;
+            }
+        }
+        impl Add for $t {
+            type Output = Self;
 
-                inv[6] = -m[0] * m[6] * m[15] + m[0] * m[7] * m[14] + m[4] * m[2] * m[15] - m[4] * m[3] * m[14] - m[12] * m[2] * m[7] + m[12] * m[3] * m[6];
+            fn add(mut self, rhs: Self) -> Self {
+                for (vector, other) in self.data.iter_mut().zip(rhs.data) {
+                    *vector += other;
+                }
+                self
+            }
+        }
+        impl AddAssign for $t {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+        impl Sub for $t {
+            type Output = Self;
 
-                inv[10] = m[0] * m[5] * m[15] - m[0] * m[7] * m[13] - m[4] * m[1] * m[15] + m[4] * m[3] * m[13] + m[12] * m[1] * m[7] - m[12] * m[3] * m[5];
+            fn sub(mut self, rhs: Self) -> Self {
+                for (vector, other) in self.data.iter_mut().zip(rhs.data) {
+                    *vector -= other;
+                }
+                self
+            }
+        }
+        impl SubAssign for $t {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+    };
+}
 
-                inv[14] = -m[0] * m[5] * m[14] + m[0] * m[6] * m[13] + m[4] * m[1] * m[14] - m[4] * m[2] * m[13] - m[12] * m[1] * m[6] + m[12] * m[2] * m[5];
+#[macro_export]
+macro_rules! impl_matrix3x3 {
+    ($t: ty, $f: ty) => {
+        // Creation code for the matrix
+        #[allow(dead_code)]
+        impl $t {
+            // Create a matrix from 3 vector3s
+            pub fn new(vec1: Vector3<$f>, vec2: Vector3<$f>, vec3: Vector3<$f>) -> Self {
+                Matrix3x3 { data: [vec1, vec2, vec3] }
+            }
+            // Create a 2D translation matrix
+            pub fn from_translation(position: Vector2<$f>) -> Self {
+                Matrix3x3::<$f>::new(Vector3::X, Vector3::Y, Vector3::new(position[0], position[1], 1.0))
+            }
+            // Create a rotation matrix
+            pub fn from_quaternion(quat: &Quaternion<$f>) -> Self {
+                let qx = quat[0];
+                let qy = quat[1];
+                let qz = quat[2];
+                let qw = quat[3];
+                let vec1 = Vector3::<$f>::new(1.0 - 2.0 * qy * qy - 2.0 * qz * qz, 2.0 * qx * qy + 2.0 * qz * qw, 2.0 * qx * qz - 2.0 * qy * qw);
+                let vec2 = Vector3::<$f>::new(2.0 * qx * qy - 2.0 * qz * qw, 1.0 - 2.0 * qx * qx - 2.0 * qz * qz, 2.0 * qy * qz + 2.0 * qx * qw);
+                let vec3 = Vector3::<$f>::new(2.0 * qx * qz + 2.0 * qy * qw, 2.0 * qy * qz - 2.0 * qx * qw, 1.0 - 2.0 * qx * qx - 2.0 * qy * qy);
+                Matrix3x3::<$f>::new(vec1, vec2, vec3)
+            }
+            // Create a scale matrix
+            pub fn from_scale(scale: Vector3<$f>) -> Self {
+                Matrix3x3::<$f>::new(Vector3::X * scale.x, Vector3::Y * scale.y, Vector3::Z * scale.z)
+            }
+            // Multiply a matrix by this matrix
+            pub fn mul_mat3x3(&self, other: Matrix3x3<$f>) -> Self {
+                let mut output: Self = Self::IDENTITY;
+                for x in 0..3 {
+                    *output.get_vec_mut(x) = self.mul_vector(other.get_vec(x));
+                }
+                output
+            }
+            // Get the adjugate (transposed cofactor matrix) of this matrix
+            fn adjugate(&self) -> Self {
+                let m = *self;
+                let mut inv = Self::default();
+                inv[0] = m[4] * m[8] - m[5] * m[7];
+                inv[1] = m[2] * m[7] - m[1] * m[8];
+                inv[2] = m[1] * m[5] - m[2] * m[4];
+                inv[3] = m[5] * m[6] - m[3] * m[8];
+                inv[4] = m[0] * m[8] - m[2] * m[6];
+                inv[5] = m[2] * m[3] - m[0] * m[5];
+                inv[6] = m[3] * m[7] - m[4] * m[6];
+                inv[7] = m[1] * m[6] - m[0] * m[7];
+                inv[8] = m[0] * m[4] - m[1] * m[3];
+                inv
+            }
+            // Get the determinant of this matrix
+            pub fn determinant(&self) -> $f {
+                self.get_vec(0).dot(self.get_vec(1).cross(*self.get_vec(2)))
+            }
+            // Return the inverse of this matrix, or an error containing the determinant if the matrix is singular
+            pub fn try_inverse(&self) -> Result<Self, SingularMatrix<$f>> {
+                let m = *self;
+                let inv = self.adjugate();
+                let det = m[0] * inv[0] + m[1] * inv[3] + m[2] * inv[6];
+                // Scale aware check, since the determinant can never be bigger than the product of the collumn lengths
+                let bound: $f = (0..3).map(|i| self.get_vec(i).length()).product();
+                if det.abs() <= bound * <$f>::EPSILON {
+                    return Err(SingularMatrix { determinant: det });
+                }
 
-                inv[3] = -m[1] * m[6] * m[11] + m[1] * m[7] * m[10] + m[5] * m[2] * m[11] - m[5] * m[3] * m[10] - m[9] * m[2] * m[7] + m[9] * m[3] * m[6];
+                let mut output = inv;
+                for i in 0..9 {
+                    output[i] = inv[i] * (1.0 / det);
+                }
+                Ok(output)
+            }
+            // Return the inverse of this matrix, or false if the matrix is singular
+            pub fn inverse(&self, inverse: &mut Self) -> bool {
+                match self.try_inverse() {
+                    Ok(output) => {
+                        *inverse = output;
+                        true
+                    }
+                    Err(_) => false,
+                }
+            }
+            // Inversed, which returns the identity matrix if the matrix is singular. Use try_inverse to catch that case
+            pub fn inversed(&self) -> Self {
+                self.try_inverse().unwrap_or(Self::IDENTITY)
+            }
+            // Transform a 3D vector by the matrix
+            pub fn mul_vector(&self, vector: &Vector3<$f>) -> Vector3<$f> {
+                *self.get_vec(0) * vector.x + *self.get_vec(1) * vector.y + *self.get_vec(2) * vector.z
+            }
+            // Transform a 2D point by the matrix, basically create a 3D vector out of it with the Z component being 1.0
+            pub fn mul_point(&self, point: &Vector2<$f>) -> Vector2<$f> {
+                self.mul_vector(&Vector3::new(point.x, point.y, 1.0)).get2([0, 1])
+            }
+        }
 
-                inv[7] = m[0] * m[6] * m[11] - m[0] * m[7] * m[10] - m[4] * m[2] * m[11] + m[4] * m[3] * m[10] + m[8] * m[2] * m[7] - m[8] * m[3] * m[6];
+        // Operators
+        $crate::impl_matrix_operators!($t, $f, Vector3<$f>, mul_mat3x3);
+        impl Mul<Vector2<$f>> for $t {
+            type Output = Vector2<$f>;
 
-                inv[11] = -m[0] * m[5] * m[11] + m[0] * m[7] * m[9] + m[4] * m[1] * m[11] - m[4] * m[3] * m[9] - m[8] * m[1] * m[7] + m[8] * m[3] * m[5];
+            fn mul(self, rhs: Vector2<$f>) -> Vector2<$f> {
+                self.mul_point(&rhs)
+            }
+        }
+    };
+}
 
-                inv[15] = m[0] * m[5] * m[10] - m[0] * m[6] * m[9] - m[4] * m[1] * m[10] + m[4] * m[2] * m[9] + m[8] * m[1] * m[6] - m[8] * m[2] * m[5];
-                let det = m[0] * inv[0] + m[1] * inv[4] + m[2] * inv[8] + m[3] * inv[12];
-                // Not valid
-                if det == 0.0 {
-                    return false;
+#[macro_export]
+macro_rules! impl_matrix2x2 {
+    ($t: ty, $f: ty) => {
+        // Creation code for the matrix
+        #[allow(dead_code)]
+        impl $t {
+            // Create a matrix from 2 vector2s
+            pub fn new(vec1: Vector2<$f>, vec2: Vector2<$f>) -> Self {
+                Matrix2x2 { data: [vec1, vec2] }
+            }
+            // Create a 2D rotation matrix using an angle in radians
+            pub fn from_rotation(angle: $f) -> Self {
+                let (sin, cos) = angle.sin_cos();
+                Matrix2x2::<$f>::new(Vector2::new(cos, sin), Vector2::new(-sin, cos))
+            }
+            // Create a scale matrix
+            pub fn from_scale(scale: Vector2<$f>) -> Self {
+                Matrix2x2::<$f>::new(Vector2::X * scale.x, Vector2::Y * scale.y)
+            }
+            // Multiply a matrix by this matrix
+            pub fn mul_mat2x2(&self, other: Matrix2x2<$f>) -> Self {
+                let mut output: Self = Self::IDENTITY;
+                for x in 0..2 {
+                    *output.get_vec_mut(x) = self.mul_vector(other.get_vec(x));
+                }
+                output
+            }
+            // Get the determinant of this matrix
+            pub fn determinant(&self) -> $f {
+                self[0] * self[3] - self[1] * self[2]
+            }
+            // Return the inverse of this matrix, or an error containing the determinant if the matrix is singular
+            pub fn try_inverse(&self) -> Result<Self, SingularMatrix<$f>> {
+                let m = *self;
+                let det = self.determinant();
+                // Scale aware check, since the determinant can never be bigger than the product of the collumn lengths
+                let bound: $f = (0..2).map(|i| self.get_vec(i).length()).product();
+                if det.abs() <= bound * <$f>::EPSILON {
+                    return Err(SingularMatrix { determinant: det });
                 }
 
-                for i in 0..16 {
-                    inverse[i] = inv[i] * (1.0 / det);
+                let inv = [m[3], -m[1], -m[2], m[0]];
+                let mut output = Self::IDENTITY;
+                for i in 0..4 {
+                    output[i] = inv[i] * (1.0 / det);
                 }
-                true
+                Ok(output)
             }
-            // Inversed
-            pub fn inversed(&self) -> Self {
-                let mut output = Self::IDENTITY;
-                self.inverse(&mut output);
-                output
+            // Return the inverse of this matrix, or false if the matrix is singular
+            pub fn inverse(&self, inverse: &mut Self) -> bool {
+                match self.try_inverse() {
+                    Ok(output) => {
+                        *inverse = output;
+                        true
+                    }
+                    Err(_) => false,
+                }
             }
-            // Transform a 4D vector by the matrix
-            pub fn mul_vector(&self, vector: &Vector4<$f>) -> Vector4<$f> {
-                // Multiply the vector by this matrix
-                let x = self.get_vec(0).dot(*vector);
-                let y = self.get_vec(1).dot(*vector);
-                let z = self.get_vec(2).dot(*vector);
-                let w = self.get_vec(3).dot(*vector);
-                Vector4::<$f>::new(x, y, z, w)
+            // Inversed, which returns the identity matrix if the matrix is singular. Use try_inverse to catch that case
+            pub fn inversed(&self) -> Self {
+                self.try_inverse().unwrap_or(Self::IDENTITY)
             }
-            // Transform a 3D point by the matrix, basically create a 4D vector out of it with the W component being 1.0
-            pub fn mul_point(&self, point: &Vector3<$f>) -> Vector3<$f> {
-                self.mul_vector(&Vector4::new(point.x, point.y, point.z, 1.0)).get3([0, 1, 2])
+            // Transform a 2D vector by the matrix
+            pub fn mul_vector(&self, vector: &Vector2<$f>) -> Vector2<$f> {
+                *self.get_vec(0) * vector.x + *self.get_vec(1) * vector.y
             }
         }
+
+        // Operators
+        $crate::impl_matrix_operators!($t, $f, Vector2<$f>, mul_mat2x2);
     };
 }
 
@@ -898,6 +993,108 @@ macro_rules! impl_quaternion {
                 */
                 output
             }
+            // Create a quaternion from a rotation matrix, which could either be a 3x3 or a 4x4 matrix
+            // https://www.euclideanspace.com/maths/geometry/rotations/conversions/matrixToQuaternion/index.htm
+            pub fn from_rotation_matrix<M: Into<Matrix3x3<$f>>>(matrix: M) -> Quaternion<$f> {
+                let matrix: Matrix3x3<$f> = matrix.into();
+                // Row, Collumn
+                let m = |row: usize, col: usize| matrix.data[col][row];
+                let trace = m(0, 0) + m(1, 1) + m(2, 2);
+                let data = if trace > 0.0 {
+                    let s = (trace + 1.0).sqrt() * 2.0;
+                    Vector4::new((m(2, 1) - m(1, 2)) / s, (m(0, 2) - m(2, 0)) / s, (m(1, 0) - m(0, 1)) / s, 0.25 * s)
+                } else if m(0, 0) > m(1, 1) && m(0, 0) > m(2, 2) {
+                    let s = (1.0 + m(0, 0) - m(1, 1) - m(2, 2)).sqrt() * 2.0;
+                    Vector4::new(0.25 * s, (m(0, 1) + m(1, 0)) / s, (m(0, 2) + m(2, 0)) / s, (m(2, 1) - m(1, 2)) / s)
+                } else if m(1, 1) > m(2, 2) {
+                    let s = (1.0 + m(1, 1) - m(0, 0) - m(2, 2)).sqrt() * 2.0;
+                    Vector4::new((m(0, 1) + m(1, 0)) / s, 0.25 * s, (m(1, 2) + m(2, 1)) / s, (m(0, 2) - m(2, 0)) / s)
+                } else {
+                    let s = (1.0 + m(2, 2) - m(0, 0) - m(1, 1)).sqrt() * 2.0;
+                    Vector4::new((m(0, 2) + m(2, 0)) / s, (m(1, 2) + m(2, 1)) / s, 0.25 * s, (m(1, 0) - m(0, 1)) / s)
+                };
+                // Get rid of any leftover scale
+                Quaternion { data: data.normalized() }
+            }
+            // Create the shortest rotation that rotates the "from" direction onto the "to" direction
+            // https://stackoverflow.com/questions/1171849/finding-quaternion-representing-the-rotation-from-one-vector-to-another
+            pub fn from_rotation_arc(from: Vector3<$f>, to: Vector3<$f>) -> Quaternion<$f> {
+                let from = from.normalized();
+                let to = to.normalized();
+                let dot = from.dot(to);
+                // Same direction
+                if dot >= 1.0 - <$f>::EPSILON {
+                    return Self::IDENTITY;
+                }
+                // Opposite directions, so rotate by 180 degrees around any axis perpendicular to "from"
+                if dot <= -1.0 + <$f>::EPSILON {
+                    let mut axis = Vector3::<$f>::X.cross(from);
+                    if axis.length_sqrt() < 0.000001 {
+                        axis = Vector3::<$f>::Y.cross(from);
+                    }
+                    return Self::from_axis_angle(axis.normalized(), std::f64::consts::PI as $f);
+                }
+                let axis = from.cross(to);
+                Quaternion {
+                    data: Vector4::new(axis.x, axis.y, axis.z, 1.0 + dot),
+                }
+                .normalized()
+            }
+            // Create a rotation that makes the -Z axis point towards "forward" and the Y axis point towards "up", just like Matrix4x4::look_at
+            pub fn look_rotation(forward: Vector3<$f>, up: Vector3<$f>) -> Quaternion<$f> {
+                let forward = forward.normalized();
+                let right = forward.cross(up);
+                // The up vector is parallel to the forward vector, so there is no unique rotation around the forward axis
+                if right.length_sqrt() < 0.000001 {
+                    return Self::from_rotation_arc(-Vector3::<$f>::Z, forward);
+                }
+                let right = right.normalized();
+                let up = right.cross(forward);
+                Self::from_rotation_matrix(Matrix3x3::<$f>::new(right, up, -forward))
+            }
+            // Get the euler angles of this quaternion, using the same order convention as from_euler_angles
+            // When the rotation is gimbal locked, the last angle is set to 0 and the first angle takes the whole rotation
+            pub fn to_euler_angles(&self, order: EulerAnglesOrder) -> Vector3<$f> {
+                // The axii in the order of the angles operation, and whether or not that order is a cyclic permutation of XYZ
+                let (i, j, k, sign): (usize, usize, usize, $f) = match order {
+                    EulerAnglesOrder::XYZ => (0, 1, 2, 1.0),
+                    EulerAnglesOrder::XZY => (0, 2, 1, -1.0),
+                    EulerAnglesOrder::YXZ => (1, 0, 2, -1.0),
+                    EulerAnglesOrder::YZX => (1, 2, 0, 1.0),
+                    EulerAnglesOrder::ZXY => (2, 0, 1, 1.0),
+                    EulerAnglesOrder::ZYX => (2, 1, 0, -1.0),
+                };
+                let matrix = Matrix3x3::<$f>::from_quaternion(self);
+                // Row, Collumn
+                let m = |row: usize, col: usize| matrix.data[col][row];
+                let middle = (sign * m(i, k)).clamp(-1.0, 1.0);
+                let mut euler = Vector3::<$f>::ZERO;
+                euler[j] = middle.asin();
+                if middle.abs() < 1.0 - (<$f>::EPSILON * 16.0) {
+                    euler[i] = (-sign * m(j, k)).atan2(m(k, k));
+                    euler[k] = (-sign * m(i, j)).atan2(m(i, i));
+                } else {
+                    // Gimbal lock
+                    euler[i] = (sign * m(k, j)).atan2(m(j, j));
+                    euler[k] = 0.0;
+                }
+                euler
+            }
+            // Get the axis and the angle of this quaternion
+            pub fn to_axis_angle(&self) -> (Vector3<$f>, $f) {
+                let mut data = self.data.normalized();
+                // Keep the angle in the [0, PI] range
+                if data.w < 0.0 {
+                    data = -data;
+                }
+                let angle = 2.0 * data.w.clamp(-1.0, 1.0).acos();
+                let sin = (1.0 - data.w * data.w).max(0.0).sqrt();
+                // No rotation at all, so any axis works
+                if sin < <$f>::EPSILON {
+                    return (Vector3::X, 0.0);
+                }
+                (data.get3([0, 1, 2]) / sin, angle)
+            }
             // Create the quaternion from an angle and the X axis
             pub fn from_x_angle(angle: $f) -> Quaternion<$f> {
                 Self::from_axis_angle(Vector3::X, angle)
@@ -923,13 +1120,23 @@ macro_rules! impl_quaternion {
                 let vector: Vector3<$f> = self_vector.cross(point);
                 point + vector * (2.0 * self[3]) + self_vector.cross(vector) * 2.0
             }
+            // Rotate a lot of points at once. This converts the quaternion to a matrix first, since that is cheaper per point
+            pub fn transform_points(&self, points: &mut [Vector3<$f>]) {
+                let matrix = Matrix3x3::<$f>::from_quaternion(self);
+                $crate::batch::map_in_place(points, |point| matrix.mul_vector(&point));
+            }
+            // Rotate a lot of points at once, and write them into the destination slice, which must have the same length
+            pub fn transform_points_into(&self, src: &[Vector3<$f>], dst: &mut [Vector3<$f>]) {
+                let matrix = Matrix3x3::<$f>::from_quaternion(self);
+                $crate::batch::map_into(src, dst, |point| matrix.mul_vector(&point));
+            }
             // Multiply a quaternion by this quaternion
             pub fn mul_quaternion(&self, other: Quaternion<$f>) -> Quaternion<$f> {
                 // The output
                 let mut output: Self = Self::IDENTITY;
                 let other_vector = other.data.get3([0, 1, 2]);
                 let self_vector = self.data.get3([0, 1, 2]);
-                output[3] = self[3] * other[3] + self_vector.dot(other_vector);
+                output[3] = self[3] * other[3] - self_vector.dot(other_vector);
                 let new_vector: Vector3<$f> = self_vector * other[3] + other_vector * self[3] + self_vector.cross(other_vector);
                 output[0] = new_vector.x;
                 output[1] = new_vector.y;
@@ -940,16 +1147,1009 @@ macro_rules! impl_quaternion {
             pub fn normalize(&mut self) {
                 self.data.normalize();
             }
-        }
-
-        // Operators
-        impl Mul for Quaternion<$f> {
-            type Output = Quaternion<$f>;
-
-            fn mul(self, rhs: Self) -> Self::Output {
+            // Get the normalized value of this quaternion without updating it
+            pub fn normalized(self) -> Self {
+                Quaternion { data: self.data.normalized() }
+            }
+            // Get the length of this quaternion
+            pub fn length(self) -> $f {
+                self.data.length()
+            }
+            // Get the dot product between two quaternions
+            pub fn dot(self, other: Self) -> $f {
+                self.data.dot(other.data)
+            }
+            // Get the conjugate of this quaternion, which is also its inverse if it is normalized
+            pub fn conjugate(self) -> Self {
+                Quaternion {
+                    data: Vector4::new(-self[0], -self[1], -self[2], self[3]),
+                }
+            }
+            // Get the inverse of this quaternion
+            pub fn inverse(self) -> Self {
+                Quaternion {
+                    data: self.conjugate().data / self.data.length_sqrt(),
+                }
+            }
+            // Linearly interpolate between two quaternions and normalize the result, always taking the shortest path
+            pub fn nlerp(self, other: Self, t: $f) -> Self {
+                // Flip the other quaternion if it is in the opposite hemisphere
+                let other = if self.dot(other) < 0.0 { -other } else { other };
+                Quaternion {
+                    data: self.data.lerp(other.data, t).normalized(),
+                }
+            }
+            // Spherically interpolate between two quaternions, always taking the shortest path
+            // https://en.wikipedia.org/wiki/Slerp
+            pub fn slerp(self, other: Self, t: $f) -> Self {
+                self.slerp_internal(other, t, true)
+            }
+            // Interpolate between two quaternions using a spherical cubic spline, "a" and "b" being the control points of self and other
+            // The control points can be calculated using squad_control_point
+            pub fn squad(self, other: Self, a: Self, b: Self, t: $f) -> Self {
+                let first = self.slerp_internal(other, t, false);
+                let second = a.slerp_internal(b, t, false);
+                first.slerp_internal(second, 2.0 * t * (1.0 - t), false)
+            }
+            // Calculate the squad control point of a keyframe using its neighbouring keyframes
+            pub fn squad_control_point(previous: Self, current: Self, next: Self) -> Self {
+                // Make sure that the neighbours are in the same hemisphere as the current keyframe
+                let previous = if current.dot(previous) < 0.0 { -previous } else { previous };
+                let next = if current.dot(next) < 0.0 { -next } else { next };
+                let inverse = current.inverse();
+                let first = inverse.mul_quaternion(next).log();
+                let second = inverse.mul_quaternion(previous).log();
+                current.mul_quaternion(((first + second) * -0.25).exp())
+            }
+            // The natural logarithm of a unit quaternion, which results in a pure quaternion
+            pub fn log(self) -> Self {
+                let vector = self.data.get3([0, 1, 2]);
+                let length = vector.length();
+                // No rotation at all
+                if length < <$f>::EPSILON {
+                    return Quaternion { data: Vector4::ZERO };
+                }
+                let vector = vector * (length.atan2(self[3]) / length);
+                Quaternion {
+                    data: Vector4::new(vector.x, vector.y, vector.z, 0.0),
+                }
+            }
+            // The exponential of a pure quaternion, which results in a unit quaternion
+            pub fn exp(self) -> Self {
+                let vector = self.data.get3([0, 1, 2]);
+                let angle = vector.length();
+                // No rotation at all
+                if angle < <$f>::EPSILON {
+                    return Self::IDENTITY;
+                }
+                let (sin, cos) = angle.sin_cos();
+                let vector = vector * (sin / angle);
+                Quaternion {
+                    data: Vector4::new(vector.x, vector.y, vector.z, cos),
+                }
+            }
+            // Slerp, with the shortest path handling being optional since squad must not flip its inner interpolations
+            fn slerp_internal(self, other: Self, t: $f, shortest: bool) -> Self {
+                let mut other = other.data;
+                let mut cos = self.data.dot(other);
+                if shortest && cos < 0.0 {
+                    other = -other;
+                    cos = -cos;
+                }
+                // The quaternions are too close to each other, so just use a normalized lerp
+                if cos.abs() > 0.9995 {
+                    return Quaternion {
+                        data: self.data.lerp(other, t).normalized(),
+                    };
+                }
+                let angle = cos.clamp(-1.0, 1.0).acos();
+                let sin = angle.sin();
+                let first = ((1.0 - t) * angle).sin() / sin;
+                let second = (t * angle).sin() / sin;
+                Quaternion {
+                    data: self.data * first + other * second,
+                }
+            }
+        }
+
+        // Operators
+        impl Mul for Quaternion<$f> {
+            type Output = Quaternion<$f>;
+
+            fn mul(self, rhs: Self) -> Self::Output {
                 //return self.mul_quaternion(rhs);
                 rhs.mul_quaternion(self)
             }
         }
+        impl Mul<$f> for Quaternion<$f> {
+            type Output = Quaternion<$f>;
+
+            fn mul(self, rhs: $f) -> Self::Output {
+                Quaternion { data: self.data * rhs }
+            }
+        }
+        impl Mul<Vector3<$f>> for Quaternion<$f> {
+            type Output = Vector3<$f>;
+
+            fn mul(self, rhs: Vector3<$f>) -> Self::Output {
+                self.mul_point(rhs)
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_aabb3 {
+    ($t: ty, $f: ty) => {
+        impl $t {
+            // Create the smallest AABB that contains all the given points. Returns None if there are no points
+            pub fn from_points(points: &[Vector3<$f>]) -> Option<Self> {
+                let (first, rest) = points.split_first()?;
+                let mut aabb = Self::new(*first, *first);
+                for point in rest {
+                    aabb = aabb.expand_to_point(*point);
+                }
+                Some(aabb)
+            }
+            // Get the 8 corners of this AABB
+            pub fn corners(&self) -> [Vector3<$f>; 8] {
+                let mut corners = [self.min; 8];
+                for (i, corner) in corners.iter_mut().enumerate() {
+                    corner.x = if i & 1 == 0 { self.min.x } else { self.max.x };
+                    corner.y = if i & 2 == 0 { self.min.y } else { self.max.y };
+                    corner.z = if i & 4 == 0 { self.min.z } else { self.max.z };
+                }
+                corners
+            }
+            // Get the smallest AABB that contains both AABBs
+            pub fn union(&self, other: &Self) -> Self {
+                Self::new(self.min.min(other.min), self.max.max(other.max))
+            }
+            // Get the overlapping region of both AABBs, if there is one
+            pub fn intersection(&self, other: &Self) -> Option<Self> {
+                let aabb = Self::new(self.min.max(other.min), self.max.min(other.max));
+                aabb.min.elem_lte(&aabb.max).all().then(|| aabb)
+            }
+            // Check if a point is inside this AABB (borders included)
+            pub fn contains_point(&self, point: &Vector3<$f>) -> bool {
+                (point.elem_gte(&self.min) & point.elem_lte(&self.max)).all()
+            }
+            // Check if two AABBs overlap (touching counts as overlapping)
+            pub fn intersects(&self, other: &Self) -> bool {
+                (self.min.elem_lte(&other.max) & self.max.elem_gte(&other.min)).all()
+            }
+            // Get the center of this AABB
+            pub fn center(&self) -> Vector3<$f> {
+                (self.min + self.max) / 2.0
+            }
+            // Get the full size of this AABB
+            pub fn size(&self) -> Vector3<$f> {
+                self.max - self.min
+            }
+            // Get the half size of this AABB
+            pub fn extents(&self) -> Vector3<$f> {
+                self.size() / 2.0
+            }
+            // Get the total area of the 6 faces
+            pub fn surface_area(&self) -> $f {
+                let size = self.size();
+                2.0 * (size.x * size.y + size.x * size.z + size.y * size.z)
+            }
+            // Get the volume of this AABB
+            pub fn volume(&self) -> $f {
+                let size = self.size();
+                size.x * size.y * size.z
+            }
+            // Grow the AABB by "amount" in every direction
+            pub fn expand(&self, amount: $f) -> Self {
+                Self::new(self.min - amount, self.max + amount)
+            }
+            // Grow the AABB so it contains the given point
+            pub fn expand_to_point(&self, point: Vector3<$f>) -> Self {
+                Self::new(self.min.min(point), self.max.max(point))
+            }
+            // Get the AABB that contains this AABB after it got transformed by a matrix
+            pub fn transform(&self, matrix: &Matrix4x4<$f>) -> Self {
+                let corners = self.corners().map(|corner| matrix.mul_point(&corner));
+                Self::from_points(&corners).unwrap()
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_aabb2 {
+    ($t: ty, $f: ty) => {
+        impl $t {
+            // Create the smallest AABB that contains all the given points. Returns None if there are no points
+            pub fn from_points(points: &[Vector2<$f>]) -> Option<Self> {
+                let (first, rest) = points.split_first()?;
+                let mut aabb = Self::new(*first, *first);
+                for point in rest {
+                    aabb = aabb.expand_to_point(*point);
+                }
+                Some(aabb)
+            }
+            // Get the 4 corners of this AABB
+            pub fn corners(&self) -> [Vector2<$f>; 4] {
+                [self.min, Vector2::new(self.max.x, self.min.y), Vector2::new(self.min.x, self.max.y), self.max]
+            }
+            // Get the smallest AABB that contains both AABBs
+            pub fn union(&self, other: &Self) -> Self {
+                Self::new(self.min.min(other.min), self.max.max(other.max))
+            }
+            // Get the overlapping region of both AABBs, if there is one
+            pub fn intersection(&self, other: &Self) -> Option<Self> {
+                let aabb = Self::new(self.min.max(other.min), self.max.min(other.max));
+                aabb.min.elem_lte(&aabb.max).all().then(|| aabb)
+            }
+            // Check if a point is inside this AABB (borders included)
+            pub fn contains_point(&self, point: &Vector2<$f>) -> bool {
+                (point.elem_gte(&self.min) & point.elem_lte(&self.max)).all()
+            }
+            // Check if two AABBs overlap (touching counts as overlapping)
+            pub fn intersects(&self, other: &Self) -> bool {
+                (self.min.elem_lte(&other.max) & self.max.elem_gte(&other.min)).all()
+            }
+            // Get the center of this AABB
+            pub fn center(&self) -> Vector2<$f> {
+                (self.min + self.max) / 2.0
+            }
+            // Get the full size of this AABB
+            pub fn size(&self) -> Vector2<$f> {
+                self.max - self.min
+            }
+            // Get the half size of this AABB
+            pub fn extents(&self) -> Vector2<$f> {
+                self.size() / 2.0
+            }
+            // Get the length of the border of this AABB
+            pub fn perimeter(&self) -> $f {
+                let size = self.size();
+                2.0 * (size.x + size.y)
+            }
+            // Get the area of this AABB
+            pub fn area(&self) -> $f {
+                let size = self.size();
+                size.x * size.y
+            }
+            // Grow the AABB by "amount" in every direction
+            pub fn expand(&self, amount: $f) -> Self {
+                Self::new(self.min - amount, self.max + amount)
+            }
+            // Grow the AABB so it contains the given point
+            pub fn expand_to_point(&self, point: Vector2<$f>) -> Self {
+                Self::new(self.min.min(point), self.max.max(point))
+            }
+            // Get the AABB that contains this AABB after it got transformed by a 2D matrix
+            pub fn transform(&self, matrix: &Matrix3x3<$f>) -> Self {
+                let corners = self.corners().map(|corner| matrix.mul_point(&corner));
+                Self::from_points(&corners).unwrap()
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_ray {
+    ($t: ty, $f: ty) => {
+        impl $t {
+            // Get the point at a specific distance along the ray
+            pub fn at(&self, t: $f) -> Vector3<$f> {
+                self.origin + self.direction * t
+            }
+            // Intersect the ray with an AABB using the slab method
+            // If the ray starts inside the AABB, the exit point gets returned instead
+            // A ray with a zero direction that starts inside the AABB hits it at a distance of 0 with a zero normal
+            // https://tavianator.com/2011/ray_box.html
+            pub fn intersect_aabb(&self, aabb: &Aabb<$f>) -> Option<RayHit<$f>> {
+                let mut enter: $f = <$f>::NEG_INFINITY;
+                let mut exit: $f = <$f>::INFINITY;
+                let mut enter_axis = 0;
+                let mut exit_axis = 0;
+                for i in 0..3 {
+                    if self.direction[i] == 0.0 {
+                        // Parallel to the slab, so we must already be between its planes
+                        if self.origin[i] < aabb.min[i] || self.origin[i] > aabb.max[i] {
+                            return None;
+                        }
+                        continue;
+                    }
+                    let t1 = (aabb.min[i] - self.origin[i]) / self.direction[i];
+                    let t2 = (aabb.max[i] - self.origin[i]) / self.direction[i];
+                    let (near, far) = if t1 < t2 { (t1, t2) } else { (t2, t1) };
+                    if near > enter {
+                        enter = near;
+                        enter_axis = i;
+                    }
+                    if far < exit {
+                        exit = far;
+                        exit_axis = i;
+                    }
+                }
+                if enter > exit || exit < 0.0 {
+                    return None;
+                }
+                // A ray without a direction never leaves the box, so it only hits it right at its origin, where there is no face to take the normal from
+                if exit == <$f>::INFINITY {
+                    return Some(RayHit { distance: 0.0, normal: Vector3::ZERO });
+                }
+                // The normal of the face we entered from faces against the ray, the one we exit from faces along it
+                let (distance, axis, sign) = if enter >= 0.0 { (enter, enter_axis, -1.0) } else { (exit, exit_axis, 1.0) };
+                let mut normal = Vector3::<$f>::ZERO;
+                normal[axis] = if self.direction[axis] > 0.0 { sign } else { -sign };
+                Some(RayHit { distance, normal })
+            }
+            // Intersect the ray with a sphere
+            // If the ray starts inside the sphere, the exit point gets returned instead
+            pub fn intersect_sphere(&self, sphere: &Sphere<$f>) -> Option<RayHit<$f>> {
+                let offset = self.origin - sphere.center;
+                let a = self.direction.dot(self.direction);
+                let b = offset.dot(self.direction);
+                let c = offset.dot(offset) - sphere.radius * sphere.radius;
+                let discriminant = b * b - a * c;
+                if discriminant < 0.0 || a == 0.0 {
+                    return None;
+                }
+                let root = discriminant.sqrt();
+                let near = (-b - root) / a;
+                let far = (-b + root) / a;
+                let distance = if near >= 0.0 { near } else { far };
+                if distance < 0.0 {
+                    return None;
+                }
+                let normal = (self.at(distance) - sphere.center) / sphere.radius;
+                Some(RayHit { distance, normal })
+            }
+            // Intersect the ray with a plane, from both sides. The normal of the hit is the normal of the plane
+            pub fn intersect_plane(&self, plane: &Plane<$f>) -> Option<RayHit<$f>> {
+                let denominator = plane.normal.dot(self.direction);
+                // Parallel to the plane
+                if denominator.abs() < <$f>::EPSILON {
+                    return None;
+                }
+                let t = -plane.signed_distance(&self.origin) / denominator;
+                (t >= 0.0).then_some(RayHit { distance: t, normal: plane.normal })
+            }
+            // Intersect the ray with a triangle, from both sides. The normal follows the counter clockwise winding of the vertices
+            // https://en.wikipedia.org/wiki/M%C3%B6ller%E2%80%93Trumbore_intersection_algorithm
+            pub fn intersect_triangle(&self, a: Vector3<$f>, b: Vector3<$f>, c: Vector3<$f>) -> Option<RayHit<$f>> {
+                let edge1 = b - a;
+                let edge2 = c - a;
+                let h = self.direction.cross(edge2);
+                let det = edge1.dot(h);
+                // Parallel to the triangle
+                if det.abs() < <$f>::EPSILON {
+                    return None;
+                }
+                let inverse = 1.0 / det;
+                let s = self.origin - a;
+                let u = inverse * s.dot(h);
+                if !(0.0..=1.0).contains(&u) {
+                    return None;
+                }
+                let q = s.cross(edge1);
+                let v = inverse * self.direction.dot(q);
+                if v < 0.0 || u + v > 1.0 {
+                    return None;
+                }
+                let distance = inverse * edge2.dot(q);
+                (distance >= 0.0).then(|| RayHit {
+                    distance,
+                    normal: edge1.cross(edge2).normalized(),
+                })
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_plane {
+    ($t: ty, $f: ty) => {
+        impl $t {
+            // Create a plane that goes through 3 points, with the normal following their counter clockwise winding
+            pub fn from_points(a: Vector3<$f>, b: Vector3<$f>, c: Vector3<$f>) -> Self {
+                let normal = (b - a).cross(c - a).normalized();
+                Self::new(normal, normal.dot(a))
+            }
+            // Create a plane using its normal and any point on it
+            pub fn from_normal_and_point(normal: Vector3<$f>, point: Vector3<$f>) -> Self {
+                Self::new(normal, normal.dot(point))
+            }
+            // Get the signed distance of a point from the plane, positive if it is in front of the plane
+            // This is only a real distance if the normal is normalized
+            pub fn signed_distance(&self, point: &Vector3<$f>) -> $f {
+                self.normal.dot(*point) - self.distance
+            }
+            // Project a point onto the plane
+            pub fn project_point(&self, point: &Vector3<$f>) -> Vector3<$f> {
+                *point - self.normal * (self.signed_distance(point) / self.normal.length_sqrt())
+            }
+            // Normalize the plane so that its normal has a length of 1
+            pub fn normalize(&mut self) {
+                *self = self.normalized();
+            }
+            // Get the normalized value of the current plane without updating it
+            pub fn normalized(&self) -> Self {
+                let length = self.normal.length();
+                Self::new(self.normal / length, self.distance / length)
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_frustum {
+    ($t: ty, $f: ty) => {
+        impl $t {
+            // Extract the frustum planes out of a view projection matrix, using OpenGL clip space
+            // https://www.gamedevs.org/uploads/fast-extraction-viewing-frustum-planes-from-world-view-projection-matrix.pdf
+            pub fn from_matrix(matrix: &Matrix4x4<$f>) -> Self {
+                let row = |i: usize| Vector4::<$f>::new(matrix.data[0][i], matrix.data[1][i], matrix.data[2][i], matrix.data[3][i]);
+                let last = row(3);
+                let plane = |vector: Vector4<$f>| Plane::<$f>::new(vector.get3([0, 1, 2]), -vector.w).normalized();
+                Self {
+                    planes: [
+                        plane(last + row(0)),
+                        plane(last - row(0)),
+                        plane(last + row(1)),
+                        plane(last - row(1)),
+                        plane(last + row(2)),
+                        plane(last - row(2)),
+                    ],
+                }
+            }
+            // Check if a point is inside the frustum. Points that lie exactly on a plane count as inside
+            pub fn test_point(&self, point: &Vector3<$f>) -> Containment {
+                if self.planes.iter().any(|plane| plane.signed_distance(point) < 0.0) {
+                    Containment::Outside
+                } else {
+                    Containment::Inside
+                }
+            }
+            // Check if a sphere is inside, outside, or intersecting the frustum
+            pub fn test_sphere(&self, sphere: &Sphere<$f>) -> Containment {
+                let mut output = Containment::Inside;
+                for plane in self.planes.iter() {
+                    let distance = plane.signed_distance(&sphere.center);
+                    if distance < -sphere.radius {
+                        return Containment::Outside;
+                    } else if distance < sphere.radius {
+                        output = Containment::Intersecting;
+                    }
+                }
+                output
+            }
+            // Check if an AABB is inside, outside, or intersecting the frustum
+            // This is conservative, so AABBs near the corners of the frustum might be reported as intersecting even though they are outside
+            pub fn test_aabb(&self, aabb: &Aabb<$f>) -> Containment {
+                let mut output = Containment::Inside;
+                for plane in self.planes.iter() {
+                    // The corners that are the furthest along and against the normal of the plane
+                    let positive = plane.normal.elem_gte(&Vector3::ZERO).select(&aabb.max, &aabb.min);
+                    let negative = plane.normal.elem_gte(&Vector3::ZERO).select(&aabb.min, &aabb.max);
+                    if plane.signed_distance(&positive) < 0.0 {
+                        return Containment::Outside;
+                    } else if plane.signed_distance(&negative) < 0.0 {
+                        output = Containment::Intersecting;
+                    }
+                }
+                output
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_sphere {
+    ($t: ty, $f: ty) => {
+        impl $t {
+            // Create a bounding sphere that contains all the given points using Ritter's algorithm. Returns None if there are no points
+            // The result is not always the smallest possible sphere, but it is usually within a few percents of it
+            // https://en.wikipedia.org/wiki/Bounding_sphere#Ritter's_bounding_sphere
+            pub fn from_points(points: &[Vector3<$f>]) -> Option<Self> {
+                let first = *points.first()?;
+                let furthest = |from: Vector3<$f>| points.iter().copied().fold(from, |a, b| if from.distance(b) > from.distance(a) { b } else { a });
+                let y = furthest(first);
+                let z = furthest(y);
+                let mut sphere = Self::new((y + z) / 2.0, y.distance(z) / 2.0);
+                for point in points {
+                    let distance = sphere.center.distance(*point);
+                    if distance > sphere.radius {
+                        let radius = (sphere.radius + distance) / 2.0;
+                        sphere.center += (*point - sphere.center) * ((radius - sphere.radius) / distance);
+                        sphere.radius = radius;
+                    }
+                }
+                Some(sphere)
+            }
+            // Check if a point is inside the sphere
+            pub fn contains_point(&self, point: &Vector3<$f>) -> bool {
+                self.center.distance(*point) <= self.radius
+            }
+            // Get the closest point on (or inside) the sphere
+            pub fn closest_point(&self, point: &Vector3<$f>) -> Vector3<$f> {
+                let offset = *point - self.center;
+                let distance = offset.length();
+                if distance <= self.radius {
+                    *point
+                } else {
+                    self.center + offset * (self.radius / distance)
+                }
+            }
+            // Check if two spheres overlap
+            pub fn intersects_sphere(&self, other: &Self) -> bool {
+                self.center.distance(other.center) <= self.radius + other.radius
+            }
+            // Check if this sphere overlaps with a capsule
+            pub fn intersects_capsule(&self, capsule: &Capsule<$f>) -> bool {
+                capsule.intersects_sphere(self)
+            }
+            // Check if this sphere overlaps with an OBB
+            pub fn intersects_obb(&self, obb: &Obb<$f>) -> bool {
+                obb.intersects_sphere(self)
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_capsule {
+    ($t: ty, $f: ty) => {
+        impl $t {
+            // Get the closest point to "point" on the inner segment of the capsule
+            pub fn closest_point_on_segment(&self, point: &Vector3<$f>) -> Vector3<$f> {
+                let segment = self.end - self.start;
+                let length = segment.length_sqrt();
+                // Degenerate capsule, which is just a sphere
+                if length == 0.0 {
+                    return self.start;
+                }
+                let t = ((*point - self.start).dot(segment) / length).clamp(0.0, 1.0);
+                self.start + segment * t
+            }
+            // Check if a point is inside the capsule
+            pub fn contains_point(&self, point: &Vector3<$f>) -> bool {
+                self.closest_point_on_segment(point).distance(*point) <= self.radius
+            }
+            // Get the closest point on (or inside) the capsule
+            pub fn closest_point(&self, point: &Vector3<$f>) -> Vector3<$f> {
+                Sphere::<$f>::new(self.closest_point_on_segment(point), self.radius).closest_point(point)
+            }
+            // Check if this capsule overlaps with a sphere
+            pub fn intersects_sphere(&self, sphere: &Sphere<$f>) -> bool {
+                self.closest_point_on_segment(&sphere.center).distance(sphere.center) <= self.radius + sphere.radius
+            }
+            // Check if two capsules overlap
+            pub fn intersects_capsule(&self, other: &Self) -> bool {
+                let (a, b) = Self::closest_points_between_segments(self.start, self.end, other.start, other.end);
+                a.distance(b) <= self.radius + other.radius
+            }
+            // Check if this capsule overlaps with an OBB
+            pub fn intersects_obb(&self, obb: &Obb<$f>) -> bool {
+                // Work in the local space of the OBB, where it is just an AABB centered around the origin
+                let point = Self::closest_point_on_segment_to_box(obb.to_local(&self.start), obb.to_local(&self.end), obb.half_extents);
+                point.distance(point.clamp(-obb.half_extents, obb.half_extents)) <= self.radius
+            }
+            // Get the closest point on a segment to an AABB centered around the origin
+            // The squared distance along the segment is a piecewise quadratic function, whose pieces are split where the segment crosses the planes of the faces
+            // Inside each piece every axis is either within the box or against the same face, so we can minimize each piece analytically
+            fn closest_point_on_segment_to_box(start: Vector3<$f>, end: Vector3<$f>, half_extents: Vector3<$f>) -> Vector3<$f> {
+                let direction = end - start;
+                let mut splits: [$f; 8] = [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0];
+                for i in 0..3 {
+                    if direction[i] != 0.0 {
+                        splits[1 + i * 2] = ((-half_extents[i] - start[i]) / direction[i]).clamp(0.0, 1.0);
+                        splits[2 + i * 2] = ((half_extents[i] - start[i]) / direction[i]).clamp(0.0, 1.0);
+                    }
+                }
+                splits.sort_by(|a, b| a.total_cmp(b));
+
+                let mut closest = start;
+                let mut closest_distance = <$f>::INFINITY;
+                for piece in splits.windows(2) {
+                    // Find which axii are outside the box inside this piece, and the faces they are closest to
+                    let middle = start + direction * ((piece[0] + piece[1]) / 2.0);
+                    let faces = middle.clamp(-half_extents, half_extents);
+                    let outside = middle.elem_eq(&faces).select(&Vector3::ZERO, &direction);
+                    // Minimize the squared distance to those faces, which is a quadratic function of t
+                    let denominator = outside.dot(outside);
+                    let t = if denominator > 0.0 { outside.dot(faces - start) / denominator } else { piece[0] };
+                    let point = start + direction * t.clamp(piece[0], piece[1]);
+                    let distance = point.distance(point.clamp(-half_extents, half_extents));
+                    if distance < closest_distance {
+                        closest = point;
+                        closest_distance = distance;
+                    }
+                }
+                closest
+            }
+            // Get the closest points between two segments
+            // Real-Time Collision Detection, Christer Ericson, 5.1.9
+            fn closest_points_between_segments(p1: Vector3<$f>, q1: Vector3<$f>, p2: Vector3<$f>, q2: Vector3<$f>) -> (Vector3<$f>, Vector3<$f>) {
+                let d1 = q1 - p1;
+                let d2 = q2 - p2;
+                let r = p1 - p2;
+                let a = d1.dot(d1);
+                let e = d2.dot(d2);
+                let f = d2.dot(r);
+                let (s, t);
+                if a <= <$f>::EPSILON && e <= <$f>::EPSILON {
+                    // Both segments are just points
+                    return (p1, p2);
+                }
+                if a <= <$f>::EPSILON {
+                    s = 0.0;
+                    t = (f / e).clamp(0.0, 1.0);
+                } else {
+                    let c = d1.dot(r);
+                    if e <= <$f>::EPSILON {
+                        t = 0.0;
+                        s = (-c / a).clamp(0.0, 1.0);
+                    } else {
+                        let b = d1.dot(d2);
+                        let denominator = a * e - b * b;
+                        // Pick any point on the first segment if they are parallel
+                        let mut s1 = if denominator != 0.0 { ((b * f - c * e) / denominator).clamp(0.0, 1.0) } else { 0.0 };
+                        let mut t1 = (b * s1 + f) / e;
+                        if t1 < 0.0 {
+                            t1 = 0.0;
+                            s1 = (-c / a).clamp(0.0, 1.0);
+                        } else if t1 > 1.0 {
+                            t1 = 1.0;
+                            s1 = ((b - c) / a).clamp(0.0, 1.0);
+                        }
+                        s = s1;
+                        t = t1;
+                    }
+                }
+                (p1 + d1 * s, p2 + d2 * t)
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_obb {
+    ($t: ty, $f: ty) => {
+        impl $t {
+            // Create an OBB out of an AABB, without any rotation
+            pub fn from_aabb(aabb: &Aabb<$f>) -> Self {
+                Self::new(aabb.center(), aabb.extents(), Quaternion::IDENTITY)
+            }
+            // Get the 3 local axii of the OBB in world space
+            pub fn axii(&self) -> [Vector3<$f>; 3] {
+                [self.orientation * Vector3::X, self.orientation * Vector3::Y, self.orientation * Vector3::Z]
+            }
+            // Convert a world space point into the local space of the OBB, where the OBB is centered around the origin
+            pub fn to_local(&self, point: &Vector3<$f>) -> Vector3<$f> {
+                self.orientation.inverse() * (*point - self.center)
+            }
+            // Convert a local space point back into world space
+            pub fn to_world(&self, point: &Vector3<$f>) -> Vector3<$f> {
+                self.orientation * *point + self.center
+            }
+            // Check if a point is inside the OBB
+            pub fn contains_point(&self, point: &Vector3<$f>) -> bool {
+                let local = self.to_local(point);
+                (local.elem_gte(&-self.half_extents) & local.elem_lte(&self.half_extents)).all()
+            }
+            // Get the closest point on (or inside) the OBB
+            pub fn closest_point(&self, point: &Vector3<$f>) -> Vector3<$f> {
+                self.to_world(&self.to_local(point).clamp(-self.half_extents, self.half_extents))
+            }
+            // Check if this OBB overlaps with a sphere
+            pub fn intersects_sphere(&self, sphere: &Sphere<$f>) -> bool {
+                self.closest_point(&sphere.center).distance(sphere.center) <= sphere.radius
+            }
+            // Check if this OBB overlaps with a capsule
+            pub fn intersects_capsule(&self, capsule: &Capsule<$f>) -> bool {
+                capsule.intersects_obb(self)
+            }
+            // Check if two OBBs overlap using the separating axis theorem
+            // Real-Time Collision Detection, Christer Ericson, 4.4.1
+            pub fn intersects_obb(&self, other: &Self) -> bool {
+                let a = self.axii();
+                let b = other.axii();
+                let offset = other.center - self.center;
+                // The radius of an OBB when projected onto an axis
+                let project = |obb: &Self, axii: &[Vector3<$f>; 3], axis: Vector3<$f>| {
+                    (0..3).map(|i| obb.half_extents[i] * axii[i].dot(axis).abs()).sum::<$f>()
+                };
+                let mut axes = [Vector3::<$f>::ZERO; 15];
+                axes[..3].copy_from_slice(&a);
+                axes[3..6].copy_from_slice(&b);
+                for i in 0..9 {
+                    axes[6 + i] = a[i / 3].cross(b[i % 3]);
+                }
+                axes.iter().all(|axis| {
+                    // Parallel edges give a null axis, which is already covered by the face axii
+                    axis.length_sqrt() <= <$f>::EPSILON || offset.dot(*axis).abs() <= project(self, &a, *axis) + project(other, &b, *axis)
+                })
+            }
+        }
+    };
+}
+
+// Generate swizzle methods like xy() or zyx() that all return the same output vector type
+#[macro_export]
+macro_rules! impl_swizzle {
+    ($t:ty, $out:ident, $([$name:ident, $($c:ident),+]),+ $(,)?) => {
+        impl<T: Copy> $t {
+            $(
+                pub fn $name(&self) -> $out<T> {
+                    $out::new($(self.$c),+)
+                }
+            )+
+        }
+        // Make sure that every method name is made of its components, so a typo in the lists fails to compile
+        $(
+            const _: () = {
+                let name = stringify!($name).as_bytes();
+                let components = concat!($(stringify!($c)),+).as_bytes();
+                assert!(name.len() == components.len(), concat!("The swizzle ", stringify!($name), " does not match its components"));
+                let mut i = 0;
+                while i < name.len() {
+                    assert!(name[i] == components[i], concat!("The swizzle ", stringify!($name), " does not match its components"));
+                    i += 1;
+                }
+            };
+        )+
+    };
+}
+
+#[macro_export]
+macro_rules! setup_floating_vecn_operations {
+    ($f: ty) => {
+        impl<const N: usize> VectorN<$f, N> {
+            // Get the distance from another vector
+            pub fn distance(self, other: Self) -> $f {
+                (self - other).length()
+            }
+            // Get the length square of the current vector (Saves us a sqrt operation)
+            pub fn length_sqrt(self) -> $f {
+                self.dot(self)
+            }
+            // Get the length of the current vector
+            pub fn length(self) -> $f {
+                self.length_sqrt().sqrt()
+            }
+            // Normalize the current vector
+            pub fn normalize(&mut self) {
+                *self = self.normalized();
+            }
+            // Get the normalized value of the current vector without updating it
+            pub fn normalized(self) -> Self {
+                self / self.length()
+            }
+            // Get the dot product between two vectors
+            pub fn dot(self, other: Self) -> $f {
+                self.data.iter().zip(other.data.iter()).map(|(a, b)| a * b).sum()
+            }
+            // Lerp between two values using T
+            pub fn lerp(self, other: Self, t: $f) -> Self {
+                self + (other - self) * t
+            }
+            // Floor
+            pub fn floor(self) -> Self {
+                Self::new(self.data.map(|x| x.floor()))
+            }
+            // Round
+            pub fn round(self) -> Self {
+                Self::new(self.data.map(|x| x.round()))
+            }
+            // Ceil
+            pub fn ceil(self) -> Self {
+                Self::new(self.data.map(|x| x.ceil()))
+            }
+        }
+    };
+}
+
+// Element wise operators for VectorN
+#[macro_export]
+macro_rules! setup_vecn_operator {
+    ($trait:ident, $fn:ident, $assign_trait:ident, $assign_fn:ident, $op:tt) => {
+        impl<T, const N: usize> $trait for VectorN<T, N>
+        where
+            T: Copy + $trait<Output = T>,
+        {
+            type Output = Self;
+
+            fn $fn(mut self, rhs: Self) -> Self::Output {
+                for i in 0..N {
+                    self[i] = self[i] $op rhs[i];
+                }
+                self
+            }
+        }
+        impl<T, const N: usize> $trait<T> for VectorN<T, N>
+        where
+            T: Copy + $trait<Output = T>,
+        {
+            type Output = Self;
+
+            fn $fn(mut self, rhs: T) -> Self::Output {
+                for i in 0..N {
+                    self[i] = self[i] $op rhs;
+                }
+                self
+            }
+        }
+        impl<T, const N: usize> $assign_trait for VectorN<T, N>
+        where
+            T: Copy + $trait<Output = T>,
+        {
+            fn $assign_fn(&mut self, rhs: Self) {
+                *self = *self $op rhs;
+            }
+        }
+        impl<T, const N: usize> $assign_trait<T> for VectorN<T, N>
+        where
+            T: Copy + $trait<Output = T>,
+        {
+            fn $assign_fn(&mut self, rhs: T) {
+                *self = *self $op rhs;
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! setup_floating_vec3xn_operations {
+    ($f: ty) => {
+        impl<const N: usize> Vector3xN<$f, N> {
+            // Get the distance from other vectors, for every lane
+            pub fn distance(self, other: Self) -> VectorN<$f, N> {
+                (self - other).length()
+            }
+            // Get the length square of the current vectors (Saves us a sqrt operation)
+            pub fn length_sqrt(self) -> VectorN<$f, N> {
+                self.dot(self)
+            }
+            // Get the length of the current vectors
+            pub fn length(self) -> VectorN<$f, N> {
+                VectorN::new(self.length_sqrt().data.map(|x| x.sqrt()))
+            }
+            // Normalize the current vectors
+            pub fn normalize(&mut self) {
+                *self = self.normalized();
+            }
+            // Get the normalized value of the current vectors without updating them
+            pub fn normalized(self) -> Self {
+                self / self.length()
+            }
+            // Get the dot product between two vectors, for every lane
+            pub fn dot(self, other: Self) -> VectorN<$f, N> {
+                self.x * other.x + self.y * other.y + self.z * other.z
+            }
+            // Get the cross product between two vectors, for every lane
+            pub fn cross(self, other: Self) -> Self {
+                let a = self;
+                let b = other;
+                Self::new(a.y * b.z - a.z * b.y, a.z * b.x - a.x * b.z, a.x * b.y - a.y * b.x)
+            }
+            // Lerp between two values using T
+            pub fn lerp(self, other: Self, t: $f) -> Self {
+                self + (other - self) * t
+            }
+        }
+    };
+}
+
+// Element wise operators for Vector3xN, either with another wide vector, a value per lane, or a single value
+#[macro_export]
+macro_rules! setup_vec3xn_operator {
+    ($trait:ident, $fn:ident, $assign_trait:ident, $assign_fn:ident, $op:tt) => {
+        impl<T, const N: usize> $trait for Vector3xN<T, N>
+        where
+            T: Copy + $trait<Output = T>,
+        {
+            type Output = Self;
+
+            fn $fn(self, rhs: Self) -> Self::Output {
+                Self::new(self.x $op rhs.x, self.y $op rhs.y, self.z $op rhs.z)
+            }
+        }
+        impl<T, const N: usize> $trait<VectorN<T, N>> for Vector3xN<T, N>
+        where
+            T: Copy + $trait<Output = T>,
+        {
+            type Output = Self;
+
+            fn $fn(self, rhs: VectorN<T, N>) -> Self::Output {
+                Self::new(self.x $op rhs, self.y $op rhs, self.z $op rhs)
+            }
+        }
+        impl<T, const N: usize> $trait<T> for Vector3xN<T, N>
+        where
+            T: Copy + $trait<Output = T>,
+        {
+            type Output = Self;
+
+            fn $fn(self, rhs: T) -> Self::Output {
+                Self::new(self.x $op rhs, self.y $op rhs, self.z $op rhs)
+            }
+        }
+        impl<T, const N: usize> $assign_trait for Vector3xN<T, N>
+        where
+            T: Copy + $trait<Output = T>,
+        {
+            fn $assign_fn(&mut self, rhs: Self) {
+                *self = *self $op rhs;
+            }
+        }
+        impl<T, const N: usize> $assign_trait<VectorN<T, N>> for Vector3xN<T, N>
+        where
+            T: Copy + $trait<Output = T>,
+        {
+            fn $assign_fn(&mut self, rhs: VectorN<T, N>) {
+                *self = *self $op rhs;
+            }
+        }
+        impl<T, const N: usize> $assign_trait<T> for Vector3xN<T, N>
+        where
+            T: Copy + $trait<Output = T>,
+        {
+            fn $assign_fn(&mut self, rhs: T) {
+                *self = *self $op rhs;
+            }
+        }
+    };
+}
+
+// Explicit endianness byte encoding and decoding, for types that can be indexed element by element
+// Only meant for fixed size element types, since the encoded bytes must mean the same thing on every platform
+#[macro_export]
+macro_rules! impl_endian_bytes {
+    ($t: ident, $count: expr, $($v: ty),*) => {
+        $(
+            impl $t<$v> {
+                // Encode every element using a conversion function
+                fn encode_bytes(&self, convert: fn($v) -> [u8; std::mem::size_of::<$v>()]) -> [u8; $count * std::mem::size_of::<$v>()] {
+                    let mut bytes = [0; $count * std::mem::size_of::<$v>()];
+                    for (i, chunk) in bytes.chunks_exact_mut(std::mem::size_of::<$v>()).enumerate() {
+                        chunk.copy_from_slice(&convert(self[i]));
+                    }
+                    bytes
+                }
+                // Decode every element using a conversion function
+                fn decode_bytes(bytes: [u8; $count * std::mem::size_of::<$v>()], convert: fn([u8; std::mem::size_of::<$v>()]) -> $v) -> Self {
+                    let mut output = Self::default();
+                    for (i, chunk) in bytes.chunks_exact(std::mem::size_of::<$v>()).enumerate() {
+                        output[i] = convert(chunk.try_into().unwrap());
+                    }
+                    output
+                }
+                // Get the elements as little endian bytes
+                pub fn to_le_bytes(&self) -> [u8; $count * std::mem::size_of::<$v>()] {
+                    self.encode_bytes(<$v>::to_le_bytes)
+                }
+                // Get the elements as big endian bytes
+                pub fn to_be_bytes(&self) -> [u8; $count * std::mem::size_of::<$v>()] {
+                    self.encode_bytes(<$v>::to_be_bytes)
+                }
+                // Create a new value from little endian bytes
+                pub fn from_le_bytes(bytes: [u8; $count * std::mem::size_of::<$v>()]) -> Self {
+                    Self::decode_bytes(bytes, <$v>::from_le_bytes)
+                }
+                // Create a new value from big endian bytes
+                pub fn from_be_bytes(bytes: [u8; $count * std::mem::size_of::<$v>()]) -> Self {
+                    Self::decode_bytes(bytes, <$v>::from_be_bytes)
+                }
+                // Write the elements to a writer, always as little endian bytes whatever the endianness of the platform
+                // Use to_be_bytes directly for big endian formats
+                pub fn write_to<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+                    writer.write_all(&self.to_le_bytes())
+                }
+                // Read a new value from a reader, always stored as little endian bytes whatever the endianness of the platform
+                // Use from_be_bytes directly for big endian formats
+                pub fn read_from<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+                    let mut bytes = [0; $count * std::mem::size_of::<$v>()];
+                    reader.read_exact(&mut bytes)?;
+                    Ok(Self::from_le_bytes(bytes))
+                }
+            }
+        )*
     };
 }
@@ -1,16 +1,39 @@
-use std::ops::{Index, IndexMut, Mul};
+use std::{
+    error::Error,
+    fmt,
+    ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign},
+};
 
 use crate::{
-    impl_matrix,
+    impl_matrix, impl_matrix2x2, impl_matrix3x3,
     types::SupportedValue,
-    vector::Swizzable,
-    vectors::{Vector3, Vector4},
+    vector::{IndexOutOfBounds, Swizzable},
+    vectors::{Vector2, Vector3, Vector4},
     Quaternion,
 };
 
+// The error returned when trying to invert a matrix that has no inverse
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SingularMatrix<T> {
+    // The determinant of the matrix, which is (almost) zero
+    pub determinant: T,
+}
+
+impl<T> fmt::Display for SingularMatrix<T>
+where
+    T: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "matrix is singular and cannot be inverted (determinant: {})", self.determinant)
+    }
+}
+
+impl<T> Error for SingularMatrix<T> where T: fmt::Debug + fmt::Display {}
+
 // A simple f32 matrix made of 4 f32/f64 vectors
 // TODO: Turn this into a generic struct
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
 pub struct Matrix4x4<T>
 where
     T: SupportedValue,
@@ -36,6 +59,9 @@ where
     type Output = T;
     // Index
     fn index(&self, index: usize) -> &T {
+        if index >= 16 {
+            panic!("{}", IndexOutOfBounds::new(index, 16, "Matrix4x4"));
+        }
         let i1 = index / 4;
         let i2 = index % 4;
         let vector = &self.data[i1];
@@ -50,6 +76,9 @@ where
 {
     // Mut index
     fn index_mut(&mut self, index: usize) -> &mut T {
+        if index >= 16 {
+            panic!("{}", IndexOutOfBounds::new(index, 16, "Matrix4x4"));
+        }
         let i1 = index / 4;
         let i2 = index % 4;
         let vector = &mut self.data[i1];
@@ -97,14 +126,266 @@ where
     }
 }
 
+#[cfg(not(feature = "simd"))]
 impl_matrix!(Matrix4x4<f32>, f32);
+#[cfg(feature = "simd")]
+impl_matrix!(Matrix4x4<f32>, f32, simd);
 impl_matrix!(Matrix4x4<f64>, f64);
+crate::impl_endian_bytes!(Matrix4x4, 16, f32, f64);
+
+// A simple 3x3 matrix made of 3 f32/f64 vectors
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct Matrix3x3<T>
+where
+    T: SupportedValue,
+{
+    pub data: [Vector3<T>; 3],
+}
+
+// Default
+impl<T> Default for Matrix3x3<T>
+where
+    T: SupportedValue + Sized,
+{
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+// Indexer
+impl<T> Index<usize> for Matrix3x3<T>
+where
+    T: SupportedValue,
+{
+    type Output = T;
+    // Index
+    fn index(&self, index: usize) -> &T {
+        if index >= 9 {
+            panic!("{}", IndexOutOfBounds::new(index, 9, "Matrix3x3"));
+        }
+        let i1 = index / 3;
+        let i2 = index % 3;
+        let vector = &self.data[i1];
+        &vector[i2]
+    }
+}
+
+// Mut indexer
+impl<T> IndexMut<usize> for Matrix3x3<T>
+where
+    T: SupportedValue,
+{
+    // Mut index
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        if index >= 9 {
+            panic!("{}", IndexOutOfBounds::new(index, 9, "Matrix3x3"));
+        }
+        let i1 = index / 3;
+        let i2 = index % 3;
+        let vector = &mut self.data[i1];
+        &mut vector[i2]
+    }
+}
+
+impl<T> Matrix3x3<T>
+where
+    T: SupportedValue,
+{
+    // Identity matrix
+    pub const IDENTITY: Self = Matrix3x3 {
+        data: [Vector3::<T>::X, Vector3::<T>::Y, Vector3::<T>::Z],
+    };
+    // Transpose the matrix
+    pub fn transpose(&mut self) {
+        self.data = self.transposed().data;
+    }
+    // Return the transpose of this matrix
+    pub fn transposed(&self) -> Self {
+        let mut output = Self::IDENTITY;
+        for x in 0..3 {
+            for y in 0..3 {
+                let m: &mut T = &mut output[x + y * 3];
+                *m = self[y + x * 3];
+            }
+        }
+        output
+    }
+    // Get the "n" vector
+    pub fn get_vec(&self, n: usize) -> &Vector3<T> {
+        &self.data[n]
+    }
+    // Get the "n" vector mutably
+    pub fn get_vec_mut(&mut self, n: usize) -> &mut Vector3<T> {
+        &mut self.data[n]
+    }
+}
+
+impl_matrix3x3!(Matrix3x3<f32>, f32);
+impl_matrix3x3!(Matrix3x3<f64>, f64);
+
+// A simple 2x2 matrix made of 2 f32/f64 vectors
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct Matrix2x2<T>
+where
+    T: SupportedValue,
+{
+    pub data: [Vector2<T>; 2],
+}
+
+// Default
+impl<T> Default for Matrix2x2<T>
+where
+    T: SupportedValue + Sized,
+{
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+// Indexer
+impl<T> Index<usize> for Matrix2x2<T>
+where
+    T: SupportedValue,
+{
+    type Output = T;
+    // Index
+    fn index(&self, index: usize) -> &T {
+        if index >= 4 {
+            panic!("{}", IndexOutOfBounds::new(index, 4, "Matrix2x2"));
+        }
+        let i1 = index / 2;
+        let i2 = index % 2;
+        let vector = &self.data[i1];
+        &vector[i2]
+    }
+}
 
-// Multiply this matrix by another matrix
-impl Mul for Matrix4x4<f32> {
-    type Output = Self;
+// Mut indexer
+impl<T> IndexMut<usize> for Matrix2x2<T>
+where
+    T: SupportedValue,
+{
+    // Mut index
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        if index >= 4 {
+            panic!("{}", IndexOutOfBounds::new(index, 4, "Matrix2x2"));
+        }
+        let i1 = index / 2;
+        let i2 = index % 2;
+        let vector = &mut self.data[i1];
+        &mut vector[i2]
+    }
+}
 
-    fn mul(self, rhs: Self) -> Self {
-        self.mul_mat4x4(rhs)
+impl<T> Matrix2x2<T>
+where
+    T: SupportedValue,
+{
+    // Identity matrix
+    pub const IDENTITY: Self = Matrix2x2 {
+        data: [Vector2::<T>::X, Vector2::<T>::Y],
+    };
+    // Transpose the matrix
+    pub fn transpose(&mut self) {
+        self.data = self.transposed().data;
     }
-}
\ No newline at end of file
+    // Return the transpose of this matrix
+    pub fn transposed(&self) -> Self {
+        let mut output = Self::IDENTITY;
+        for x in 0..2 {
+            for y in 0..2 {
+                let m: &mut T = &mut output[x + y * 2];
+                *m = self[y + x * 2];
+            }
+        }
+        output
+    }
+    // Get the "n" vector
+    pub fn get_vec(&self, n: usize) -> &Vector2<T> {
+        &self.data[n]
+    }
+    // Get the "n" vector mutably
+    pub fn get_vec_mut(&mut self, n: usize) -> &mut Vector2<T> {
+        &mut self.data[n]
+    }
+}
+
+impl_matrix2x2!(Matrix2x2<f32>, f32);
+impl_matrix2x2!(Matrix2x2<f64>, f64);
+
+// Conversions between the matrix sizes
+// Going down keeps the upper left part, going up embeds the matrix inside an identity matrix
+impl<T> From<Matrix4x4<T>> for Matrix3x3<T>
+where
+    T: SupportedValue,
+{
+    fn from(matrix: Matrix4x4<T>) -> Self {
+        let mut output = Self::IDENTITY;
+        for x in 0..3 {
+            for y in 0..3 {
+                output.data[x][y] = matrix.data[x][y];
+            }
+        }
+        output
+    }
+}
+impl<T> From<Matrix3x3<T>> for Matrix4x4<T>
+where
+    T: SupportedValue,
+{
+    fn from(matrix: Matrix3x3<T>) -> Self {
+        let mut output = Self::IDENTITY;
+        for x in 0..3 {
+            for y in 0..3 {
+                output.data[x][y] = matrix.data[x][y];
+            }
+        }
+        output
+    }
+}
+impl<T> From<Matrix3x3<T>> for Matrix2x2<T>
+where
+    T: SupportedValue,
+{
+    fn from(matrix: Matrix3x3<T>) -> Self {
+        let mut output = Self::IDENTITY;
+        for x in 0..2 {
+            for y in 0..2 {
+                output.data[x][y] = matrix.data[x][y];
+            }
+        }
+        output
+    }
+}
+impl<T> From<Matrix2x2<T>> for Matrix3x3<T>
+where
+    T: SupportedValue,
+{
+    fn from(matrix: Matrix2x2<T>) -> Self {
+        let mut output = Self::IDENTITY;
+        for x in 0..2 {
+            for y in 0..2 {
+                output.data[x][y] = matrix.data[x][y];
+            }
+        }
+        output
+    }
+}
+impl<T> From<Matrix4x4<T>> for Matrix2x2<T>
+where
+    T: SupportedValue,
+{
+    fn from(matrix: Matrix4x4<T>) -> Self {
+        Matrix3x3::from(matrix).into()
+    }
+}
+impl<T> From<Matrix2x2<T>> for Matrix4x4<T>
+where
+    T: SupportedValue,
+{
+    fn from(matrix: Matrix2x2<T>) -> Self {
+        Matrix3x3::from(matrix).into()
+    }
+}
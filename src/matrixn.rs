@@ -0,0 +1,196 @@
+use std::ops::{Add, Index, IndexMut, Mul};
+
+use crate::{
+    types::SupportedValue,
+    vector::IndexOutOfBounds,
+    vectors::{Vector4, VectorN},
+    Matrix2x2, Matrix3x3, Matrix4x4,
+};
+
+// A matrix with any amount of rows (R) and collumns (C), stored as C collumn vectors just like Matrix4x4
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct Matrix<T, const R: usize, const C: usize> {
+    pub data: [VectorN<T, R>; C],
+}
+
+// Common rectangular matrices
+pub type Matrix3x4<T> = Matrix<T, 3, 4>;
+pub type Matrix4x3<T> = Matrix<T, 4, 3>;
+
+impl<T, const R: usize, const C: usize> Matrix<T, R, C>
+where
+    T: SupportedValue,
+{
+    // A matrix full of zeros
+    pub const ZERO: Self = Self { data: [VectorN::ZERO; C] };
+    // Create a matrix out of its collumns
+    pub const fn new(data: [VectorN<T, R>; C]) -> Self {
+        Self { data }
+    }
+    // Create a matrix with ones on its diagonal, even if it is not square
+    pub fn identity() -> Self {
+        let mut output = Self::ZERO;
+        for i in 0..R.min(C) {
+            output[(i, i)] = T::ONE;
+        }
+        output
+    }
+    // Get the "n" collumn
+    pub fn col(&self, n: usize) -> VectorN<T, R> {
+        self.data[n]
+    }
+    // Get the "n" row
+    pub fn row(&self, n: usize) -> VectorN<T, C> {
+        VectorN::new(std::array::from_fn(|i| self[(n, i)]))
+    }
+    // Return the transpose of this matrix
+    pub fn transposed(&self) -> Matrix<T, C, R> {
+        Matrix::new(std::array::from_fn(|i| self.row(i)))
+    }
+}
+
+// Indexer using (row, collumn)
+impl<T, const R: usize, const C: usize> Index<(usize, usize)> for Matrix<T, R, C> {
+    type Output = T;
+    // Index
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        if row >= R || col >= C {
+            panic!("{}", IndexOutOfBounds::new(col * R + row, R * C, "Matrix"));
+        }
+        &self.data[col].data[row]
+    }
+}
+
+// Mut indexer using (row, collumn)
+impl<T, const R: usize, const C: usize> IndexMut<(usize, usize)> for Matrix<T, R, C> {
+    // Mut index
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        if row >= R || col >= C {
+            panic!("{}", IndexOutOfBounds::new(col * R + row, R * C, "Matrix"));
+        }
+        &mut self.data[col].data[row]
+    }
+}
+
+// Multiply a RxK matrix by a KxC matrix, which results in a RxC matrix
+impl<T, const R: usize, const K: usize, const C: usize> Mul<Matrix<T, K, C>> for Matrix<T, R, K>
+where
+    T: SupportedValue + Add<Output = T> + Mul<Output = T>,
+{
+    type Output = Matrix<T, R, C>;
+
+    fn mul(self, rhs: Matrix<T, K, C>) -> Self::Output {
+        Matrix::new(std::array::from_fn(|i| self * rhs.col(i)))
+    }
+}
+
+// Transform a vector by the matrix
+impl<T, const R: usize, const C: usize> Mul<VectorN<T, C>> for Matrix<T, R, C>
+where
+    T: SupportedValue + Add<Output = T> + Mul<Output = T>,
+{
+    type Output = VectorN<T, R>;
+
+    fn mul(self, rhs: VectorN<T, C>) -> Self::Output {
+        let mut output = VectorN::<T, R>::ZERO;
+        for i in 0..C {
+            output += self.data[i] * rhs[i];
+        }
+        output
+    }
+}
+
+// Conversions from and to the fixed size matrices
+impl<T> From<Matrix4x4<T>> for Matrix<T, 4, 4>
+where
+    T: SupportedValue,
+{
+    fn from(matrix: Matrix4x4<T>) -> Self {
+        Self::from(matrix.data)
+    }
+}
+impl<T> From<Matrix<T, 4, 4>> for Matrix4x4<T>
+where
+    T: SupportedValue,
+{
+    fn from(matrix: Matrix<T, 4, 4>) -> Self {
+        Matrix4x4 { data: matrix.into() }
+    }
+}
+// Affine matrices drop the last row, which is always [0, 0, 0, 1]
+impl<T> From<Matrix4x4<T>> for Matrix3x4<T>
+where
+    T: SupportedValue,
+{
+    fn from(matrix: Matrix4x4<T>) -> Self {
+        Self::new(matrix.data.map(|collumn| VectorN::new([collumn.x, collumn.y, collumn.z])))
+    }
+}
+impl<T> From<Matrix3x4<T>> for Matrix4x4<T>
+where
+    T: SupportedValue,
+{
+    fn from(matrix: Matrix3x4<T>) -> Self {
+        let mut output = Matrix4x4::<T>::IDENTITY;
+        for i in 0..4 {
+            let [x, y, z] = matrix.data[i].data;
+            output.data[i] = Vector4::new(x, y, z, output.data[i].w);
+        }
+        output
+    }
+}
+impl<T> From<Matrix3x3<T>> for Matrix<T, 3, 3>
+where
+    T: SupportedValue,
+{
+    fn from(matrix: Matrix3x3<T>) -> Self {
+        Self::new(matrix.data.map(VectorN::from))
+    }
+}
+impl<T> From<Matrix<T, 3, 3>> for Matrix3x3<T>
+where
+    T: SupportedValue,
+{
+    fn from(matrix: Matrix<T, 3, 3>) -> Self {
+        Matrix3x3 {
+            data: matrix.data.map(Into::into),
+        }
+    }
+}
+impl<T> From<Matrix2x2<T>> for Matrix<T, 2, 2>
+where
+    T: SupportedValue,
+{
+    fn from(matrix: Matrix2x2<T>) -> Self {
+        Self::new(matrix.data.map(VectorN::from))
+    }
+}
+impl<T> From<Matrix<T, 2, 2>> for Matrix2x2<T>
+where
+    T: SupportedValue,
+{
+    fn from(matrix: Matrix<T, 2, 2>) -> Self {
+        Matrix2x2 {
+            data: matrix.data.map(Into::into),
+        }
+    }
+}
+
+// Conversions from and to arrays of Vector4 collumns
+impl<T, const C: usize> From<[Vector4<T>; C]> for Matrix<T, 4, C>
+where
+    T: SupportedValue,
+{
+    fn from(collumns: [Vector4<T>; C]) -> Self {
+        Self::new(collumns.map(VectorN::from))
+    }
+}
+impl<T, const C: usize> From<Matrix<T, 4, C>> for [Vector4<T>; C]
+where
+    T: SupportedValue,
+{
+    fn from(matrix: Matrix<T, 4, C>) -> Self {
+        matrix.data.map(Into::into)
+    }
+}
@@ -0,0 +1,61 @@
+use crate::{
+    layout::{Std140Mat3, Std140Vec3},
+    matrix::{Matrix2x2, Matrix3x3, Matrix4x4},
+    matrixn::Matrix,
+    quaternion::Quaternion,
+    types::SupportedValue,
+    vectors::{Vector2, Vector3, Vector3xN, Vector4, VectorN},
+};
+use bytemuck::{Pod, Zeroable};
+
+// All of these are #[repr(C)] and only made of T, so they never contain any padding
+unsafe impl<T: Zeroable> Zeroable for Vector2<T> {}
+unsafe impl<T: Pod> Pod for Vector2<T> {}
+unsafe impl<T: Zeroable> Zeroable for Vector3<T> {}
+unsafe impl<T: Pod> Pod for Vector3<T> {}
+unsafe impl<T: Zeroable> Zeroable for Vector4<T> {}
+unsafe impl<T: Pod> Pod for Vector4<T> {}
+unsafe impl<T: Zeroable, const N: usize> Zeroable for VectorN<T, N> {}
+unsafe impl<T: Pod, const N: usize> Pod for VectorN<T, N> {}
+unsafe impl<T: Zeroable, const N: usize> Zeroable for Vector3xN<T, N> {}
+unsafe impl<T: Pod, const N: usize> Pod for Vector3xN<T, N> {}
+unsafe impl<T: SupportedValue + Zeroable> Zeroable for Matrix4x4<T> {}
+unsafe impl<T: SupportedValue + Pod> Pod for Matrix4x4<T> {}
+unsafe impl<T: SupportedValue + Zeroable> Zeroable for Matrix3x3<T> {}
+unsafe impl<T: SupportedValue + Pod> Pod for Matrix3x3<T> {}
+unsafe impl<T: SupportedValue + Zeroable> Zeroable for Matrix2x2<T> {}
+unsafe impl<T: SupportedValue + Pod> Pod for Matrix2x2<T> {}
+unsafe impl<T: Zeroable, const R: usize, const C: usize> Zeroable for Matrix<T, R, C> {}
+unsafe impl<T: Pod, const R: usize, const C: usize> Pod for Matrix<T, R, C> {}
+unsafe impl<T: Zeroable> Zeroable for Quaternion<T> {}
+unsafe impl<T: Pod> Pod for Quaternion<T> {}
+// The padding of the std140 types is stored in a real field, so these do not contain any uninitialized bytes either
+unsafe impl Zeroable for Std140Vec3 {}
+unsafe impl Pod for Std140Vec3 {}
+unsafe impl Zeroable for Std140Mat3 {}
+unsafe impl Pod for Std140Mat3 {}
+
+// Safely view a value (or a slice of values) as raw bytes, for uploading vertex and uniform buffers
+pub trait AsBytes {
+    fn as_bytes(&self) -> &[u8];
+}
+impl<T: Pod> AsBytes for T {
+    fn as_bytes(&self) -> &[u8] {
+        bytemuck::bytes_of(self)
+    }
+}
+impl<T: Pod> AsBytes for [T] {
+    fn as_bytes(&self) -> &[u8] {
+        bytemuck::cast_slice(self)
+    }
+}
+
+// Reinterpret a slice as a slice of another type, like a slice of Vector3<f32> as a slice of f32
+// This panics if the alignment or the total size in bytes does not work out
+pub fn cast_slice<A: Pod, B: Pod>(slice: &[A]) -> &[B] {
+    bytemuck::cast_slice(slice)
+}
+// Same as cast_slice, but mutably
+pub fn cast_slice_mut<A: Pod, B: Pod>(slice: &mut [A]) -> &mut [B] {
+    bytemuck::cast_slice_mut(slice)
+}
@@ -1,9 +1,10 @@
-use std::ops::{Index, IndexMut, Mul};
+use std::ops::{Add, Index, IndexMut, Mul, Neg, Sub};
 
-use crate::{impl_quaternion, types::SupportedValue, Swizzable, Vector3, Vector4};
+use crate::{impl_quaternion, types::SupportedValue, IndexOutOfBounds, Matrix3x3, Swizzable, Vector3, Vector4};
 
 // A quaternion that represents a rotation
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
 pub struct Quaternion<T> {
     data: Vector4<T>,
 }
@@ -26,7 +27,10 @@ where
     type Output = T;
     // Index
     fn index(&self, index: usize) -> &Self::Output {
-        &self.data[index]
+        match self.data.get(index) {
+            Some(value) => value,
+            None => panic!("{}", IndexOutOfBounds::new(index, 4, "Quaternion")),
+        }
     }
 }
 
@@ -37,11 +41,15 @@ where
 {
     // Mut index
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        &mut self.data[index]
+        match self.data.get_mut(index) {
+            Some(value) => value,
+            None => panic!("{}", IndexOutOfBounds::new(index, 4, "Quaternion")),
+        }
     }
 }
 
 // The order of the angles operations when creating a Quaternion from euler angles
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum EulerAnglesOrder {
     XYZ,
     XZY,
@@ -57,7 +65,68 @@ where
 {
     // Identity
     pub const IDENTITY: Self = Self { data: Vector4::<T>::W };
+    // Create a quaternion directly from its raw components, W being the scalar part
+    pub const fn from_xyzw(x: T, y: T, z: T, w: T) -> Self {
+        Self { data: Vector4::new(x, y, z, w) }
+    }
+    // Get the X component
+    pub fn x(&self) -> T {
+        self.data.x
+    }
+    // Get the Y component
+    pub fn y(&self) -> T {
+        self.data.y
+    }
+    // Get the Z component
+    pub fn z(&self) -> T {
+        self.data.z
+    }
+    // Get the W component (scalar part)
+    pub fn w(&self) -> T {
+        self.data.w
+    }
+    // Get the vector part
+    pub fn xyz(&self) -> Vector3<T> {
+        self.data.get3([0, 1, 2])
+    }
+}
+
+// Negation, which results in the same rotation
+impl<T> Neg for Quaternion<T>
+where
+    T: SupportedValue + Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self { data: -self.data }
+    }
+}
+
+// Component wise addition
+impl<T> Add for Quaternion<T>
+where
+    T: SupportedValue + Add<Output = T>,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self { data: self.data + rhs.data }
+    }
+}
+
+// Component wise subtraction
+impl<T> Sub for Quaternion<T>
+where
+    T: SupportedValue + Sub<Output = T>,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self { data: self.data - rhs.data }
+    }
 }
 
 impl_quaternion!(Quaternion<f32>, f32);
 impl_quaternion!(Quaternion<f64>, f64);
+crate::impl_endian_bytes!(Quaternion, 4, f32, f64);
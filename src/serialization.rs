@@ -0,0 +1,93 @@
+use crate::{
+    matrix::{Matrix2x2, Matrix3x3, Matrix4x4},
+    quaternion::Quaternion,
+    types::SupportedValue,
+    vectors::{Vector2, Vector3, Vector4},
+};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+// Vectors are stored as compact sequences ([x, y, z]) instead of maps, since the element names never change
+impl<T: Serialize> Serialize for Vector2<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        [&self.x, &self.y].serialize(serializer)
+    }
+}
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Vector2<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let [x, y] = <[T; 2]>::deserialize(deserializer)?;
+        Ok(Vector2::new(x, y))
+    }
+}
+impl<T: Serialize> Serialize for Vector3<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        [&self.x, &self.y, &self.z].serialize(serializer)
+    }
+}
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Vector3<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let [x, y, z] = <[T; 3]>::deserialize(deserializer)?;
+        Ok(Vector3::new(x, y, z))
+    }
+}
+impl<T: Serialize> Serialize for Vector4<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        [&self.x, &self.y, &self.z, &self.w].serialize(serializer)
+    }
+}
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Vector4<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let [x, y, z, w] = <[T; 4]>::deserialize(deserializer)?;
+        Ok(Vector4::new(x, y, z, w))
+    }
+}
+
+// Matrices are stored as an array of collumns, each collumn being a vector
+impl<T: SupportedValue + Serialize> Serialize for Matrix4x4<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.data.serialize(serializer)
+    }
+}
+impl<'de, T: SupportedValue + Deserialize<'de>> Deserialize<'de> for Matrix4x4<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Matrix4x4 {
+            data: <[Vector4<T>; 4]>::deserialize(deserializer)?,
+        })
+    }
+}
+impl<T: SupportedValue + Serialize> Serialize for Matrix3x3<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.data.serialize(serializer)
+    }
+}
+impl<'de, T: SupportedValue + Deserialize<'de>> Deserialize<'de> for Matrix3x3<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Matrix3x3 {
+            data: <[Vector3<T>; 3]>::deserialize(deserializer)?,
+        })
+    }
+}
+impl<T: SupportedValue + Serialize> Serialize for Matrix2x2<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.data.serialize(serializer)
+    }
+}
+impl<'de, T: SupportedValue + Deserialize<'de>> Deserialize<'de> for Matrix2x2<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Matrix2x2 {
+            data: <[Vector2<T>; 2]>::deserialize(deserializer)?,
+        })
+    }
+}
+
+// Quaternions are stored as [x, y, z, w]
+impl<T: SupportedValue + Serialize> Serialize for Quaternion<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        [self.x(), self.y(), self.z(), self.w()].serialize(serializer)
+    }
+}
+impl<'de, T: SupportedValue + Deserialize<'de>> Deserialize<'de> for Quaternion<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let [x, y, z, w] = <[T; 4]>::deserialize(deserializer)?;
+        Ok(Quaternion::from_xyzw(x, y, z, w))
+    }
+}
@@ -0,0 +1,14 @@
+mod aabb;
+mod capsule;
+mod frustum;
+mod obb;
+mod plane;
+mod ray;
+mod sphere;
+pub use self::aabb::*;
+pub use self::capsule::*;
+pub use self::frustum::*;
+pub use self::obb::*;
+pub use self::plane::*;
+pub use self::ray::*;
+pub use self::sphere::*;
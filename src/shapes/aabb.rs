@@ -0,0 +1,54 @@
+use crate::{impl_aabb2, impl_aabb3, types::SupportedValue, Matrix3x3, Matrix4x4, Vector2, Vector3};
+
+// An axis aligned bounding box in 3D
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Aabb<T> {
+    pub min: Vector3<T>,
+    pub max: Vector3<T>,
+}
+
+impl<T> Aabb<T> {
+    // Create a new AABB using its min and max corners
+    pub const fn new(min: Vector3<T>, max: Vector3<T>) -> Self {
+        Self { min, max }
+    }
+}
+
+// Default
+impl<T> Default for Aabb<T>
+where
+    T: SupportedValue,
+{
+    fn default() -> Self {
+        Self::new(Vector3::ZERO, Vector3::ZERO)
+    }
+}
+
+// An axis aligned bounding box in 2D
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Aabb2<T> {
+    pub min: Vector2<T>,
+    pub max: Vector2<T>,
+}
+
+impl<T> Aabb2<T> {
+    // Create a new AABB using its min and max corners
+    pub const fn new(min: Vector2<T>, max: Vector2<T>) -> Self {
+        Self { min, max }
+    }
+}
+
+// Default
+impl<T> Default for Aabb2<T>
+where
+    T: SupportedValue,
+{
+    fn default() -> Self {
+        Self::new(Vector2::ZERO, Vector2::ZERO)
+    }
+}
+
+impl_aabb3!(Aabb<f32>, f32);
+impl_aabb3!(Aabb<f64>, f64);
+impl_aabb2!(Aabb2<f32>, f32);
+impl_aabb2!(Aabb2<f64>, f64);
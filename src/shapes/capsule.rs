@@ -0,0 +1,19 @@
+use crate::{impl_capsule, Obb, Sphere, Vector3};
+
+// A capsule, which is a line segment with a radius around it
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Capsule<T> {
+    pub start: Vector3<T>,
+    pub end: Vector3<T>,
+    pub radius: T,
+}
+
+impl<T> Capsule<T> {
+    // Create a new capsule using the two centers of its caps and its radius
+    pub const fn new(start: Vector3<T>, end: Vector3<T>, radius: T) -> Self {
+        Self { start, end, radius }
+    }
+}
+
+impl_capsule!(Capsule<f32>, f32);
+impl_capsule!(Capsule<f64>, f64);
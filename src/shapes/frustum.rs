@@ -0,0 +1,19 @@
+use crate::{impl_frustum, Aabb, Matrix4x4, Plane, Sphere, Swizzable, Vector3, Vector4};
+
+// The result of testing a shape against a volume
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Containment {
+    Inside,
+    Outside,
+    Intersecting,
+}
+
+// A camera frustum made of 6 planes whose normals point inwards
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Frustum<T> {
+    // Left, right, bottom, top, near, far
+    pub planes: [Plane<T>; 6],
+}
+
+impl_frustum!(Frustum<f32>, f32);
+impl_frustum!(Frustum<f64>, f64);
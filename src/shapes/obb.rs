@@ -0,0 +1,23 @@
+use crate::{impl_obb, Aabb, Capsule, Quaternion, Sphere, Vector3};
+
+// An oriented bounding box, which is a box that got rotated around its center
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Obb<T> {
+    pub center: Vector3<T>,
+    pub half_extents: Vector3<T>,
+    pub orientation: Quaternion<T>,
+}
+
+impl<T> Obb<T> {
+    // Create a new OBB
+    pub const fn new(center: Vector3<T>, half_extents: Vector3<T>, orientation: Quaternion<T>) -> Self {
+        Self {
+            center,
+            half_extents,
+            orientation,
+        }
+    }
+}
+
+impl_obb!(Obb<f32>, f32);
+impl_obb!(Obb<f64>, f64);
@@ -0,0 +1,18 @@
+use crate::{impl_plane, Vector3};
+
+// An infinite plane made of all the points where normal.dot(point) == distance
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Plane<T> {
+    pub normal: Vector3<T>,
+    pub distance: T,
+}
+
+impl<T> Plane<T> {
+    // Create a new plane using its normal and its distance from the origin along that normal
+    pub const fn new(normal: Vector3<T>, distance: T) -> Self {
+        Self { normal, distance }
+    }
+}
+
+impl_plane!(Plane<f32>, f32);
+impl_plane!(Plane<f64>, f64);
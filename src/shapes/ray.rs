@@ -0,0 +1,27 @@
+use crate::{impl_ray, Aabb, Plane, Sphere, Vector3};
+
+// A ray that starts at an origin and goes infinitely towards a direction
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ray<T> {
+    pub origin: Vector3<T>,
+    pub direction: Vector3<T>,
+}
+
+impl<T> Ray<T> {
+    // Create a new ray. The hit distances are measured in multiples of the direction's length
+    pub const fn new(origin: Vector3<T>, direction: Vector3<T>) -> Self {
+        Self { origin, direction }
+    }
+}
+
+// The result of a successful ray intersection test
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RayHit<T> {
+    // The distance along the ray, so the hit point is ray.at(distance)
+    pub distance: T,
+    // The outward normal of the surface at the hit point
+    pub normal: Vector3<T>,
+}
+
+impl_ray!(Ray<f32>, f32);
+impl_ray!(Ray<f64>, f64);
@@ -0,0 +1,18 @@
+use crate::{impl_sphere, Capsule, Obb, Vector3};
+
+// A sphere made of a center and a radius
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sphere<T> {
+    pub center: Vector3<T>,
+    pub radius: T,
+}
+
+impl<T> Sphere<T> {
+    // Create a new sphere
+    pub const fn new(center: Vector3<T>, radius: T) -> Self {
+        Self { center, radius }
+    }
+}
+
+impl_sphere!(Sphere<f32>, f32);
+impl_sphere!(Sphere<f64>, f64);
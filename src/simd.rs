@@ -0,0 +1,210 @@
+use crate::{matrix::Matrix4x4, vectors::Vector4};
+
+// The SIMD kernels used by Vector4<f32> and Matrix4x4<f32> when the "simd" feature is enabled
+// SSE2 on x86/x86_64, NEON on aarch64, and a plain scalar fallback on every other target
+// Horizontal sums add the lanes as (x + y) + (z + w), while the scalar code adds them one after the other
+// So dot products, lengths and normalized vectors can differ in the last bits from a build without the feature
+
+// Implement a lane wise operator for the F32x4 of the current backend
+macro_rules! impl_lanes_operator {
+    ($trait: ident, $fn: ident, $op: path) => {
+        impl std::ops::$trait for F32x4 {
+            type Output = Self;
+
+            fn $fn(self, rhs: Self) -> Self {
+                Self(unsafe { $op(self.0, rhs.0) })
+            }
+        }
+    };
+}
+
+#[cfg(any(target_arch = "x86_64", all(target_arch = "x86", target_feature = "sse2")))]
+#[allow(unused_unsafe)]
+mod backend {
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::*;
+
+    // 4 f32 lanes stored in an SSE register
+    #[derive(Clone, Copy)]
+    pub struct F32x4(pub __m128);
+
+    impl F32x4 {
+        pub fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+            Self(unsafe { _mm_setr_ps(x, y, z, w) })
+        }
+        pub fn splat(value: f32) -> Self {
+            Self(unsafe { _mm_set1_ps(value) })
+        }
+        pub fn to_array(self) -> [f32; 4] {
+            let mut output = [0.0; 4];
+            unsafe { _mm_storeu_ps(output.as_mut_ptr(), self.0) };
+            output
+        }
+        // Sum all the lanes together as (x + y) + (z + w)
+        pub fn sum(self) -> f32 {
+            unsafe {
+                let shuffled = _mm_shuffle_ps::<0b10_11_00_01>(self.0, self.0);
+                let sums = _mm_add_ps(self.0, shuffled);
+                let high = _mm_movehl_ps(shuffled, sums);
+                _mm_cvtss_f32(_mm_add_ss(sums, high))
+            }
+        }
+        // Rotate the first 3 lanes, turning XYZW into YZXW
+        pub fn yzxw(self) -> Self {
+            Self(unsafe { _mm_shuffle_ps::<0b11_00_10_01>(self.0, self.0) })
+        }
+    }
+    impl_lanes_operator!(Add, add, _mm_add_ps);
+    impl_lanes_operator!(Sub, sub, _mm_sub_ps);
+    impl_lanes_operator!(Mul, mul, _mm_mul_ps);
+    impl_lanes_operator!(Div, div, _mm_div_ps);
+}
+
+#[cfg(target_arch = "aarch64")]
+#[allow(unused_unsafe)]
+mod backend {
+    use std::arch::aarch64::*;
+
+    // 4 f32 lanes stored in a NEON register
+    #[derive(Clone, Copy)]
+    pub struct F32x4(pub float32x4_t);
+
+    impl F32x4 {
+        pub fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+            let array = [x, y, z, w];
+            Self(unsafe { vld1q_f32(array.as_ptr()) })
+        }
+        pub fn splat(value: f32) -> Self {
+            Self(unsafe { vdupq_n_f32(value) })
+        }
+        pub fn to_array(self) -> [f32; 4] {
+            let mut output = [0.0; 4];
+            unsafe { vst1q_f32(output.as_mut_ptr(), self.0) };
+            output
+        }
+        // Sum all the lanes together as (x + y) + (z + w)
+        pub fn sum(self) -> f32 {
+            unsafe { vaddvq_f32(self.0) }
+        }
+        // Rotate the first 3 lanes, turning XYZW into YZXW
+        pub fn yzxw(self) -> Self {
+            let [x, y, z, w] = self.to_array();
+            Self::new(y, z, x, w)
+        }
+    }
+    impl_lanes_operator!(Add, add, vaddq_f32);
+    impl_lanes_operator!(Sub, sub, vsubq_f32);
+    impl_lanes_operator!(Mul, mul, vmulq_f32);
+    impl_lanes_operator!(Div, div, vdivq_f32);
+}
+
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64", all(target_arch = "x86", target_feature = "sse2"))))]
+#[allow(unused_unsafe)]
+mod backend {
+    // 4 f32 lanes, for targets that do not have a supported SIMD instruction set
+    #[derive(Clone, Copy)]
+    pub struct F32x4(pub [f32; 4]);
+
+    impl F32x4 {
+        pub fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+            Self([x, y, z, w])
+        }
+        pub fn splat(value: f32) -> Self {
+            Self([value; 4])
+        }
+        pub fn to_array(self) -> [f32; 4] {
+            self.0
+        }
+        // Sum all the lanes together as (x + y) + (z + w)
+        pub fn sum(self) -> f32 {
+            (self.0[0] + self.0[1]) + (self.0[2] + self.0[3])
+        }
+        // Rotate the first 3 lanes, turning XYZW into YZXW
+        pub fn yzxw(self) -> Self {
+            let [x, y, z, w] = self.0;
+            Self([y, z, x, w])
+        }
+    }
+    fn add(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+        std::array::from_fn(|i| a[i] + b[i])
+    }
+    fn sub(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+        std::array::from_fn(|i| a[i] - b[i])
+    }
+    fn mul(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+        std::array::from_fn(|i| a[i] * b[i])
+    }
+    fn div(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+        std::array::from_fn(|i| a[i] / b[i])
+    }
+    impl_lanes_operator!(Add, add, add);
+    impl_lanes_operator!(Sub, sub, sub);
+    impl_lanes_operator!(Mul, mul, mul);
+    impl_lanes_operator!(Div, div, div);
+}
+
+use backend::F32x4;
+
+impl F32x4 {
+    fn load(vector: &Vector4<f32>) -> Self {
+        Self::new(vector.x, vector.y, vector.z, vector.w)
+    }
+    fn store(self) -> Vector4<f32> {
+        let [x, y, z, w] = self.to_array();
+        Vector4::new(x, y, z, w)
+    }
+    fn dot(self, other: Self) -> f32 {
+        (self * other).sum()
+    }
+    // Cross product of the first 3 lanes, the W lane must be zero on both sides
+    fn cross(self, other: Self) -> Self {
+        (self * other.yzxw() - self.yzxw() * other).yzxw()
+    }
+}
+
+// Vector4<f32> kernels
+pub(crate) fn dot(a: &Vector4<f32>, b: &Vector4<f32>) -> f32 {
+    F32x4::load(a).dot(F32x4::load(b))
+}
+pub(crate) fn normalized(vector: &Vector4<f32>) -> Vector4<f32> {
+    let vector = F32x4::load(vector);
+    let len = vector.dot(vector).sqrt();
+    (vector / F32x4::splat(len)).store()
+}
+
+// Matrix4x4<f32> kernels
+pub(crate) fn mul_vector(matrix: &Matrix4x4<f32>, vector: &Vector4<f32>) -> Vector4<f32> {
+    let [c0, c1, c2, c3] = matrix.data.map(|collumn| F32x4::load(&collumn));
+    (c0 * F32x4::splat(vector.x) + c1 * F32x4::splat(vector.y) + c2 * F32x4::splat(vector.z) + c3 * F32x4::splat(vector.w)).store()
+}
+pub(crate) fn mul_mat4x4(a: &Matrix4x4<f32>, b: &Matrix4x4<f32>) -> Matrix4x4<f32> {
+    // Each output collumn is a linear combination of the collumns of A
+    let [c0, c1, c2, c3] = a.data.map(|collumn| F32x4::load(&collumn));
+    let data = b.data.map(|v| (c0 * F32x4::splat(v.x) + c1 * F32x4::splat(v.y) + c2 * F32x4::splat(v.z) + c3 * F32x4::splat(v.w)).store());
+    Matrix4x4 { data }
+}
+// The adjugate, computed with 3D cross products (Eric Lengyel, Foundations of Game Engine Development, Vol. 1)
+pub(crate) fn adjugate(matrix: &Matrix4x4<f32>) -> Matrix4x4<f32> {
+    let [a, b, c, d] = matrix.data.map(|collumn| F32x4::new(collumn.x, collumn.y, collumn.z, 0.0));
+    let [x, y, z, w] = matrix.data.map(|collumn| F32x4::splat(collumn.w));
+    let s = a.cross(b);
+    let t = c.cross(d);
+    let u = a * y - b * x;
+    let v = c * w - d * z;
+
+    // The rows of the adjugate, with the last element stored separately
+    let rows = [
+        (b.cross(v) + t * y, -b.dot(t)),
+        (v.cross(a) - t * x, a.dot(t)),
+        (d.cross(u) + s * w, -d.dot(s)),
+        (u.cross(c) - s * z, c.dot(s)),
+    ]
+    .map(|(row, last)| {
+        let [x, y, z, _] = row.to_array();
+        [x, y, z, last]
+    });
+    let data = std::array::from_fn(|i| Vector4::new(rows[0][i], rows[1][i], rows[2][i], rows[3][i]));
+    Matrix4x4 { data }
+}
@@ -1,9 +1,10 @@
 #[cfg(test)]
+#[allow(clippy::module_inception)]
 mod tests {
     use crate::{
-        vector::{Swizzable, Vector},
-        vectors::{Vector2, Vector3, Vector4},
-        Matrix4x4, Quaternion, Vec3Axis,
+        vector::{IndexOutOfBounds, Swizzable, Vector},
+        vectors::{Vector2, Vector3, Vector3x4, Vector3x8, Vector4, VectorN},
+        Aabb, Aabb2, BufferLayout, Capsule, Containment, EulerAnglesOrder, Frustum, Matrix, Matrix2x2, Matrix3x3, Matrix3x4, Matrix4x3, Matrix4x4, Obb, Plane, Quaternion, Ray, SingularMatrix, Sphere, Std140Mat3, Std140Vec3, Vec3Axis, WriteToBuffer,
     };
 
     // Test if the vector swizzler works
@@ -20,6 +21,51 @@ mod tests {
         assert_eq!(a, Vector3::new(false, true, true));
         assert_eq!(a.get4([2, 1, 0, 1]), Vector4::new(true, true, false, true));
     }
+    // Test the compile time swizzles
+    #[test]
+    pub fn swizzle_methods() {
+        let v = Vector4::<i32>::new(1, 2, 3, 4);
+        assert_eq!(v.xy(), Vector2::new(1, 2));
+        assert_eq!(v.zyx(), Vector3::new(3, 2, 1));
+        assert_eq!(v.xxww(), Vector4::new(1, 1, 4, 4));
+        assert_eq!(v.wzyx(), v.get4([3, 2, 1, 0]));
+        assert_eq!(v.ywxz(), v.get4([1, 3, 0, 2]));
+        assert_eq!(v.wxy(), v.get3([3, 0, 1]));
+        let v = Vector3::<f32>::new(130.0, 52.0, 86.0);
+        assert_eq!(v.zx(), Vector2::new(86.0, 130.0));
+        assert_eq!(v.xyzx(), Vector4::new(130.0, 52.0, 86.0, 130.0));
+        let v = Vector2::<bool>::new(true, false);
+        assert_eq!(v.yx(), Vector2::new(false, true));
+        assert_eq!(v.yyx(), Vector3::new(false, false, true));
+    }
+    // Test the non panicking element access
+    #[test]
+    pub fn element_access() {
+        let mut v = Vector3::<f32>::new(1.0, 2.0, 3.0);
+        assert_eq!(v.get(2), Some(&3.0));
+        assert_eq!(v.get(3), None);
+        *v.get_mut(0).unwrap() = 5.0;
+        assert_eq!(v.x, 5.0);
+        assert!(v.get_mut(10).is_none());
+        assert_eq!(v.try_get2([2, 0]), Ok(Vector2::new(3.0, 5.0)));
+        assert_eq!(v.try_get3([0, 4, 1]), Err(IndexOutOfBounds::new(4, 3, "Vector3")));
+        assert_eq!(Vector2::<i32>::ONE.try_get4([0, 1, 1, 2]), Err(IndexOutOfBounds::new(2, 2, "Vector2")));
+        assert_eq!(Vector4::<i32>::W.get(3), Some(&1));
+        assert_eq!(
+            IndexOutOfBounds::new(4, 3, "Vector3").to_string(),
+            "index out of bounds: the len of Vector3 is 3 but the index is 4"
+        );
+    }
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len of Vector4 is 4 but the index is 4")]
+    pub fn vector_out_of_bounds() {
+        let _ = Vector4::<f32>::ZERO[4];
+    }
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len of Matrix4x4 is 16 but the index is 16")]
+    pub fn matrix_out_of_bounds() {
+        let _ = Matrix4x4::<f32>::IDENTITY[16];
+    }
     // Test the element wise comparison
     #[test]
     pub fn comparison() {
@@ -56,6 +102,12 @@ mod tests {
         assert_eq!(-Vector3::<i32>::ONE + Vector3::<i32>::Y * 2, Vector3::new(-1, 1, -1));
         assert_ne!(-Vector2::<f32>::ONE, Vector2::<f32>::ONE);
         assert_ne!(-Vector2::<f64>::ONE, Vector2::<f64>::ONE);
+
+        // The operators must stay generic over the element type, with or without the "simd" feature
+        fn add<T: Copy + std::ops::Add<Output = T>>(a: Vector4<T>, b: Vector4<T>) -> Vector4<T> {
+            a + b
+        }
+        assert_eq!(add(Vector4::<f32>::ONE, Vector4::W), Vector4::new(1.0, 1.0, 1.0, 2.0));
     }
     // Vector operations
     #[test]
@@ -67,6 +119,86 @@ mod tests {
         assert_eq!(k, Vector3::<f32>::new(-3.0, 6.0, -3.0));
         assert_eq!(Vector2::ZERO, Vector2::new(0.0, 0.0));
     }
+    // Vectors with any amount of elements
+    #[test]
+    pub fn vector_n() {
+        let a = VectorN::<f32, 5>::new([1.0, 2.0, 3.0, 4.0, 5.0]);
+        let b = VectorN::<f32, 5>::ONE;
+        assert_eq!(a + b, VectorN::new([2.0, 3.0, 4.0, 5.0, 6.0]));
+        assert_eq!(a - b * 2.0, VectorN::new([-1.0, 0.0, 1.0, 2.0, 3.0]));
+        assert_eq!(-a / 2.0, VectorN::new([-0.5, -1.0, -1.5, -2.0, -2.5]));
+        assert_eq!(a.dot(b), 15.0);
+        assert_eq!(VectorN::<f64, 8>::unit(7).length(), 1.0);
+        assert_eq!(VectorN::<f64, 2>::new([3.0, 4.0]).normalized(), VectorN::new([0.6, 0.8]));
+        assert_eq!(a.min(b * 3.0), VectorN::new([1.0, 2.0, 3.0, 3.0, 3.0]));
+        assert_eq!(a.clamp(b * 2.0, b * 4.0), VectorN::new([2.0, 2.0, 3.0, 4.0, 4.0]));
+        assert_eq!(a.elem_gt(&(b * 3.0)), VectorN::new([false, false, false, true, true]));
+        assert!(a.elem_gte(&b).all());
+        assert!(!a.elem_eq(&b).all() && a.elem_eq(&b).any());
+        assert_eq!(a.elem_lt(&(b * 3.0)).select(&a, &b), VectorN::new([1.0, 2.0, 1.0, 1.0, 1.0]));
+        let mut c = a;
+        c += b;
+        c *= 2.0;
+        assert_eq!(c, (a + b) * 2.0);
+        assert_eq!(a.to_string(), "[1, 2, 3, 4, 5]");
+        assert_eq!(a.get(5), None);
+        assert_eq!((a * 0.6).floor(), VectorN::new([0.0, 1.0, 1.0, 2.0, 3.0]));
+        let mask = VectorN::new([true, false, true]);
+        assert_eq!(!mask ^ (mask & VectorN::new([true, true, false])), VectorN::new([true, true, false]));
+        // NaN elements get ignored, like f32::min and f32::max
+        assert_eq!(VectorN::new([f32::NAN, 1.0]).min(VectorN::new([2.0, f32::NAN])), VectorN::new([2.0, 1.0]));
+        assert_eq!(Vector3::new(f32::NAN, 5.0, 1.0).max(Vector3::ONE), Vector3::new(1.0, 5.0, 1.0));
+
+        // Conversions
+        let vector: VectorN<i32, 3> = Vector3::new(1, 2, 3).into();
+        assert_eq!(vector, VectorN::new([1, 2, 3]));
+        assert_eq!(Vector3::from(vector), Vector3::new(1, 2, 3));
+        assert_eq!(Vector2::from(VectorN::from([1.0, 2.0])), Vector2::new(1.0, 2.0));
+        assert_eq!(Vector4::from(VectorN::<u8, 4>::from(Vector4::W)), Vector4::W);
+        assert_eq!(<[i32; 3]>::from(vector), [1, 2, 3]);
+    }
+    // Structure of arrays vectors
+    #[test]
+    pub fn vector_wide() {
+        let a = [Vector3::<f32>::X, Vector3::new(1.0, 2.0, 2.0), Vector3::new(0.0, -3.0, 4.0), Vector3::new(2.0, 0.0, 0.0)];
+        let b = [Vector3::<f32>::Y, Vector3::ONE, Vector3::Z, Vector3::new(-1.0, 5.0, 0.5)];
+        let wa = Vector3x4::from(a);
+        let wb = Vector3x4::from(b);
+        assert_eq!(wa.x, VectorN::new([1.0, 1.0, 0.0, 2.0]));
+        assert_eq!(<[Vector3<f32>; 4]>::from(wa), a);
+        // Every lane must match the scalar Vector3 version
+        let sum = wa + wb;
+        let dot = wa.dot(wb);
+        let cross = wa.cross(wb);
+        let normalized = wa.normalized();
+        for i in 0..4 {
+            assert_eq!(sum.lane(i), a[i] + b[i]);
+            assert_eq!(dot[i], a[i].dot(b[i]));
+            assert_eq!(cross.lane(i), a[i].cross(b[i]));
+            assert_eq!(normalized.lane(i), a[i].normalized());
+        }
+        assert_eq!(wa.length(), VectorN::new([1.0, 3.0, 5.0, 2.0]));
+        assert_eq!((wa * 2.0).lane(1), Vector3::new(2.0, 4.0, 4.0));
+        assert_eq!((wa / VectorN::new([1.0, 3.0, 5.0, 2.0])).lane(1), normalized.lane(1));
+        assert_eq!(-wa, Vector3x4::<f32>::ZERO - wa);
+        let mut mutated = wa;
+        mutated.set_lane(0, Vector3::ONE);
+        mutated += Vector3x4::ONE;
+        assert_eq!(mutated.lane(0), Vector3::new(2.0, 2.0, 2.0));
+
+        // Select whole vectors using a mask per lane, or single elements using a mask per element
+        let mask = wa.length().elem_gt(&VectorN::new([2.5; 4]));
+        let selected = Vector3x4::from_lane_mask(mask).select(&wa, &Vector3x4::ZERO);
+        assert_eq!(<[Vector3<f32>; 4]>::from(selected), [Vector3::ZERO, a[1], a[2], Vector3::ZERO]);
+        let clamped = wa.elem_gt(&Vector3x4::ONE).select(&Vector3x4::ONE, &wa);
+        assert_eq!(clamped, wa.min(Vector3x4::ONE));
+        assert!(wa.elem_lte(&Vector3x4::splat(Vector3::new(2.0, 2.0, 4.0))).all());
+        assert!(wa.elem_eq(&wa).all() && !wa.elem_eq(&wb).all());
+
+        let wide = Vector3x8::<f64>::splat(Vector3::new(3.0, 0.0, 4.0));
+        assert_eq!(wide.length(), VectorN::new([5.0; 8]));
+        assert_eq!(wide.lerp(Vector3x8::ZERO, 0.5).lane(7), Vector3::new(1.5, 0.0, 2.0));
+    }
     // Matrix multiplication
     #[test]
     pub fn matrix() {
@@ -89,7 +221,7 @@ mod tests {
         assert_eq!(mat1.get_vec(1).x, mat1[4]);
         assert_eq!(mat1.get_vec(1).y, mat1[5]);
         assert_eq!(mat1.get_vec(1).z, mat1[6]);
-        assert_eq!(Matrix4x4::IDENTITY * Matrix4x4::IDENTITY, Matrix4x4::<f32>::IDENTITY);
+        assert_eq!(Matrix4x4::<f32>::IDENTITY * Matrix4x4::IDENTITY, Matrix4x4::<f32>::IDENTITY);
         assert_eq!(Matrix4x4::<f32>::IDENTITY.transposed(), Matrix4x4::<f32>::IDENTITY.transposed());
         assert_eq!(
             Matrix4x4::<f32>::from_scale(Vector3::<f32>::ONE * 100.0).mul_point(&Vector3::<f32>::ONE),
@@ -97,6 +229,517 @@ mod tests {
         );
         assert_eq!(Matrix4x4::<f32>::IDENTITY, Matrix4x4::<f32>::IDENTITY.inversed());
     }
+    // Matrix 3x3 and 2x2
+    #[test]
+    pub fn matrix3x3() {
+        let mat = Matrix3x3::<f32>::new(Vector3::new(2.0, 0.0, 1.0), Vector3::new(1.0, 3.0, 0.0), Vector3::new(0.0, 1.0, 4.0));
+        assert_eq!(mat * Matrix3x3::IDENTITY, mat);
+        assert_eq!(mat.transposed().transposed(), mat);
+        assert_eq!(mat.mul_vector(&Vector3::X), Vector3::new(2.0, 0.0, 1.0));
+        let inverse = mat.inversed();
+        let product = mat * inverse;
+        for i in 0..9 {
+            assert!((product[i] - Matrix3x3::<f32>::IDENTITY[i]).abs() < 0.0001);
+        }
+        let mut singular = Matrix3x3::<f32>::IDENTITY;
+        assert!(!Matrix3x3::<f32>::from_scale(Vector3::new(1.0, 0.0, 1.0)).inverse(&mut singular));
+        assert_eq!(
+            Matrix3x3::<f64>::from_translation(Vector2::new(5.0, -2.0)).mul_point(&Vector2::ONE),
+            Vector2::new(6.0, -1.0)
+        );
+        let rotated = Matrix2x2::<f32>::from_rotation(90_f32.to_radians()).mul_vector(&Vector2::X);
+        assert!((rotated - Vector2::Y).length() < 0.0001);
+        let scale = Matrix2x2::<f64>::from_scale(Vector2::new(2.0, 4.0));
+        assert_eq!(scale * scale.inversed(), Matrix2x2::IDENTITY);
+
+        // Conversions
+        let big = Matrix4x4::<f32>::from_translation(Vector3::ONE) * Matrix4x4::<f32>::from_scale(Vector3::new(2.0, 3.0, 4.0));
+        let small = Matrix3x3::<f32>::from(big);
+        assert_eq!(small, Matrix3x3::<f32>::from_scale(Vector3::new(2.0, 3.0, 4.0)));
+        assert_eq!(Matrix4x4::from(small), Matrix4x4::<f32>::from_scale(Vector3::new(2.0, 3.0, 4.0)));
+        assert_eq!(Matrix2x2::from(big), Matrix2x2::<f32>::from_scale(Vector2::new(2.0, 3.0)));
+        let quaternion = Quaternion::<f32>::from_y_angle(35_f32.to_radians());
+        assert_eq!(Matrix3x3::from(Matrix4x4::<f32>::from_quaternion(&quaternion)), Matrix3x3::<f32>::from_quaternion(&quaternion));
+        let point = Matrix3x3::<f32>::from_quaternion(&quaternion).mul_vector(&Vector3::new(1.0, 2.0, 3.0));
+        assert!((point - quaternion.mul_point(Vector3::new(1.0, 2.0, 3.0))).length() < 0.0001);
+        assert_eq!(
+            Matrix4x4::<f32>::from_translation(Vector3::new(1.0, 2.0, 3.0)).mul_point(&Vector3::ONE),
+            Vector3::new(2.0, 3.0, 4.0)
+        );
+    }
+    // Matrix4x4::mul_vector used to dot the rows with the vector, so mul_point ignored translations
+    #[test]
+    pub fn matrix_column_major_transform() {
+        let translation = Matrix4x4::<f32>::from_translation(Vector3::new(1.0, 2.0, 3.0));
+        assert_eq!(translation.mul_point(&Vector3::ZERO), Vector3::new(1.0, 2.0, 3.0));
+        assert_eq!(translation.mul_vector(&Vector4::new(1.0, 1.0, 1.0, 0.0)), Vector4::new(1.0, 1.0, 1.0, 0.0));
+        let quaternion = Quaternion::<f32>::from_y_angle(35_f32.to_radians());
+        let point = Matrix4x4::<f32>::from_quaternion(&quaternion).mul_point(&Vector3::new(1.0, 2.0, 3.0));
+        assert!((point - quaternion.mul_point(Vector3::new(1.0, 2.0, 3.0))).length() < 0.0001);
+        let point = (translation * Matrix4x4::<f32>::from_quaternion(&quaternion)).mul_point(&Vector3::X);
+        assert!((point - (quaternion.mul_point(Vector3::X) + Vector3::new(1.0, 2.0, 3.0))).length() < 0.0001);
+    }
+    // Matrix operators
+    #[test]
+    pub fn matrix_operators() {
+        let rotation = Quaternion::<f64>::from_euler_angles(EulerAnglesOrder::XYZ, Vector3::new(0.3, -0.7, 1.1));
+        let a = Matrix4x4::<f64>::from_trs(Vector3::new(1.0, -2.0, 3.0), &rotation, Vector3::new(2.0, 3.0, 0.5));
+        let b = Matrix4x4::<f64>::from_perspective(0.1, 100.0, 1.5, 1.0);
+        let vector = Vector4::<f64>::new(1.0, 2.0, 3.0, 1.0);
+        assert!(((a * b) * vector - a * (b * vector)).length() < 0.000001);
+        assert_eq!(a * vector, a.mul_vector(&vector));
+        assert_eq!(a * Vector3::ONE, a.mul_point(&Vector3::ONE));
+        let mut c = a;
+        c *= b;
+        assert_eq!(c, a * b);
+        assert_eq!(a + a, a * 2.0);
+        assert_eq!(a - a, Matrix4x4::<f64>::IDENTITY * 0.0);
+        let mut d = a;
+        d += a;
+        d -= a;
+        d *= 3.0;
+        assert_eq!(d, a * 3.0);
+
+        let small = Matrix3x3::<f32>::from_quaternion(&Quaternion::<f32>::from_z_angle(0.5));
+        assert_eq!(small * Vector3::X, small.mul_vector(&Vector3::X));
+        assert_eq!(Matrix3x3::<f32>::from_translation(Vector2::ONE) * Vector2::ONE, Vector2::new(2.0, 2.0));
+        assert_eq!(Matrix2x2::<f64>::from_scale(Vector2::new(2.0, 3.0)) * Vector2::ONE, Vector2::new(2.0, 3.0));
+        assert_eq!(Matrix2x2::<f64>::IDENTITY + Matrix2x2::IDENTITY, Matrix2x2::<f64>::from_scale(Vector2::ONE * 2.0));
+    }
+    // Generic rectangular matrices
+    #[test]
+    pub fn matrix_generic() {
+        let a = Matrix3x4::<f32>::new([
+            VectorN::new([1.0, 2.0, 3.0]),
+            VectorN::new([4.0, 5.0, 6.0]),
+            VectorN::new([7.0, 8.0, 9.0]),
+            VectorN::new([10.0, 11.0, 12.0]),
+        ]);
+        assert_eq!(a[(1, 2)], 8.0);
+        assert_eq!(a.row(0), VectorN::new([1.0, 4.0, 7.0, 10.0]));
+        assert_eq!(a.col(3), VectorN::new([10.0, 11.0, 12.0]));
+        let b: Matrix4x3<f32> = a.transposed();
+        assert_eq!(b.row(3), a.col(3));
+        assert_eq!(b.transposed(), a);
+        // 3x4 * 4x3 = 3x3 and 4x3 * 3x4 = 4x4
+        let small: Matrix<f32, 3, 3> = a * b;
+        assert_eq!(small.row(0), VectorN::new([166.0, 188.0, 210.0]));
+        let big: Matrix<f32, 4, 4> = b * a;
+        assert_eq!(big[(0, 0)], 14.0);
+        assert_eq!(a * Matrix::<f32, 4, 4>::identity(), a);
+        assert_eq!(Matrix::<f32, 2, 3>::identity().row(1), VectorN::new([0.0, 1.0, 0.0]));
+        assert_eq!(a * VectorN::new([1.0, 0.0, 0.0, 1.0]), VectorN::new([11.0, 13.0, 15.0]));
+
+        // Conversions
+        let transform = Matrix4x4::<f32>::from_trs(Vector3::new(1.0, 2.0, 3.0), &Quaternion::<f32>::from_y_angle(0.5), Vector3::ONE * 2.0);
+        let affine = Matrix3x4::from(transform);
+        assert_eq!(affine.col(3), VectorN::new([1.0, 2.0, 3.0]));
+        assert_eq!(Matrix4x4::from(affine), transform);
+        let square = Matrix::<f32, 4, 4>::from(transform);
+        assert_eq!(Matrix4x4::from(square), transform);
+        let product = square * square;
+        assert_eq!(Matrix4x4::from(product), transform * transform);
+        assert_eq!(Matrix3x3::from(Matrix::from(Matrix3x3::<f64>::IDENTITY)), Matrix3x3::IDENTITY);
+        assert_eq!(Matrix2x2::from(Matrix::from(Matrix2x2::<f64>::IDENTITY)), Matrix2x2::IDENTITY);
+        let collumns: [Vector4<f32>; 4] = square.into();
+        assert_eq!(collumns, transform.data);
+        assert_eq!(Matrix::from([Vector4::<f32>::X, Vector4::Y]).row(1), VectorN::new([0.0, 1.0]));
+    }
+    // Matrix composition and decomposition
+    #[test]
+    pub fn matrix_trs() {
+        let translation = Vector3::<f32>::new(1.0, -2.0, 3.0);
+        let rotation = Quaternion::<f32>::from_euler_angles(EulerAnglesOrder::XYZ, Vector3::new(0.3, -0.7, 1.1));
+        for scale in [Vector3::new(2.0, 3.0, 0.5), Vector3::new(-2.0, 3.0, 0.5), Vector3::new(2.0, -3.0, -0.5)] {
+            let matrix = Matrix4x4::<f32>::from_trs(translation, &rotation, scale);
+            let multiplied = Matrix4x4::<f32>::from_translation(translation) * Matrix4x4::<f32>::from_quaternion(&rotation) * Matrix4x4::<f32>::from_scale(scale);
+            for i in 0..16 {
+                assert!((matrix[i] - multiplied[i]).abs() < 0.0001);
+            }
+            let (new_translation, new_rotation, new_scale) = matrix.decompose().unwrap();
+            assert!((new_translation - translation).length() < 0.0001);
+            let rebuilt = Matrix4x4::<f32>::from_trs(new_translation, &new_rotation, new_scale);
+            for i in 0..16 {
+                assert!((matrix[i] - rebuilt[i]).abs() < 0.0001);
+            }
+        }
+        let (_, new_rotation, new_scale) = Matrix4x4::<f32>::from_trs(translation, &rotation, Vector3::new(2.0, 3.0, 0.5)).decompose().unwrap();
+        assert!((new_scale - Vector3::new(2.0, 3.0, 0.5)).length() < 0.0001);
+        assert!(new_rotation.dot(rotation).abs() > 0.9999);
+
+        // Sheared, projective and degenerate matrices cannot be decomposed
+        let mut sheared = Matrix4x4::<f32>::IDENTITY;
+        sheared.get_vec_mut(1).x = 0.5;
+        assert!(sheared.decompose().is_none());
+        assert!(Matrix4x4::<f32>::from_perspective(0.1, 100.0, 1.0, 1.0).decompose().is_none());
+        assert!(Matrix4x4::<f32>::from_scale(Vector3::new(1.0, 0.0, 1.0)).decompose().is_none());
+
+        // The tolerance follows the precision of the type and the size of the matrix
+        let mut sheared = Matrix4x4::<f64>::IDENTITY;
+        sheared.get_vec_mut(1).x = 0.000001;
+        assert!(sheared.decompose().is_none());
+        let (_, _, tiny_scale) = Matrix4x4::<f32>::from_scale(Vector3::ONE * 0.00001).decompose().unwrap();
+        assert!((tiny_scale - Vector3::ONE * 0.00001).length() < 0.0000001);
+    }
+    // Transforming whole buffers of points at once
+    #[test]
+    pub fn batch_transforms() {
+        let close = |a: Vector3<f32>, b: Vector3<f32>| (a - b).length() < 0.0001;
+        // Big enough to get split between threads when using rayon
+        let points: Vec<Vector3<f32>> = (0..10_000).map(|i| Vector3::new(i as f32 * 0.001, (i % 7) as f32, -(i % 13) as f32)).collect();
+        let rotation = Quaternion::<f32>::from_euler_angles(EulerAnglesOrder::YXZ, Vector3::new(0.2, -1.1, 0.6));
+        let matrix = Matrix4x4::<f32>::from_trs(Vector3::new(4.0, -2.0, 1.0), &rotation, Vector3::new(2.0, 1.0, 0.5));
+
+        let mut transformed = points.clone();
+        matrix.transform_points(&mut transformed);
+        let mut into = vec![Vector3::ZERO; points.len()];
+        matrix.transform_points_into(&points, &mut into);
+        let mut vectors = points.clone();
+        matrix.transform_vectors(&mut vectors);
+        for i in 0..points.len() {
+            assert_eq!(transformed[i], matrix.mul_point(&points[i]));
+            assert_eq!(into[i], transformed[i]);
+            assert!(close(vectors[i] + Vector3::new(4.0, -2.0, 1.0), transformed[i]));
+        }
+
+        // Projective transforms divide by W
+        let projection = Matrix4x4::<f32>::from_perspective(0.1, 100.0, 1.0, 90_f32.to_radians());
+        let mut projected = vec![Vector3::new(0.0, 0.0, -0.1), Vector3::new(2.0, 1.0, -100.0), Vector3::new(-1.0, 1.0, -1.0)];
+        projection.transform_points_projective(&mut projected);
+        assert!((projected[0].z - -1.0).abs() < 0.0001);
+        assert!((projected[1].z - 1.0).abs() < 0.0001);
+        assert!(close(projected[1], projection.mul_point_projective(&Vector3::new(2.0, 1.0, -100.0))));
+        // A 90 degree field of view puts this point right on the corner of the screen
+        assert!(close(Vector3::new(projected[2].x, projected[2].y, 0.0), Vector3::new(-1.0, 1.0, 0.0)));
+
+        let mut rotated = points.clone();
+        rotation.transform_points(&mut rotated);
+        let mut rotated_into = vec![Vector3::ZERO; points.len()];
+        rotation.transform_points_into(&points, &mut rotated_into);
+        for i in 0..points.len() {
+            assert!(close(rotated[i], rotation.mul_point(points[i])));
+            assert_eq!(rotated_into[i], rotated[i]);
+        }
+    }
+    #[test]
+    #[should_panic(expected = "source and destination slices have different lengths")]
+    pub fn batch_transforms_length_mismatch() {
+        let mut dst = [Vector3::<f32>::ZERO; 2];
+        Matrix4x4::<f32>::IDENTITY.transform_points_into(&[Vector3::ONE; 3], &mut dst);
+    }
+    // Matrix inverse
+    #[test]
+    pub fn matrix_inverse() {
+        let close = |a: Matrix4x4<f32>, b: Matrix4x4<f32>| (0..16).all(|i| (a[i] - b[i]).abs() < 0.0001);
+        // The collumns are linearly dependant
+        let singular = Matrix4x4::<f32>::new(
+            Vector4::new(1.0, 2.0, 3.0, 4.0),
+            Vector4::new(5.0, 6.0, 7.0, 8.0),
+            Vector4::new(9.0, 10.0, 11.0, 12.0),
+            Vector4::new(13.0, 14.0, 15.0, 16.0),
+        );
+        assert_eq!(singular.determinant(), 0.0);
+        assert!(singular.try_inverse().is_err());
+        let mut output = Matrix4x4::<f32>::IDENTITY;
+        assert!(!singular.inverse(&mut output));
+        assert_eq!(Matrix4x4::<f64>::from_scale(Vector3::new(2.0, 0.0, 1.0)).try_inverse(), Err(SingularMatrix { determinant: 0.0 }));
+        assert_eq!(SingularMatrix { determinant: 0.0 }.to_string(), "matrix is singular and cannot be inverted (determinant: 0)");
+        assert_eq!(Matrix3x3::<f32>::from_scale(Vector3::new(2.0, 3.0, 4.0)).determinant(), 24.0);
+        assert_eq!(Matrix2x2::<f32>::from_scale(Vector2::new(2.0, 3.0)).determinant(), 6.0);
+        assert!(Matrix2x2::<f32>::new(Vector2::ONE, Vector2::ONE).try_inverse().is_err());
+        // Tiny but perfectly valid matrices must not count as singular
+        let tiny = Matrix4x4::<f32>::from_scale(Vector3::ONE * 0.001);
+        assert!(close(tiny * tiny.try_inverse().unwrap(), Matrix4x4::IDENTITY));
+
+        let rotation = Quaternion::<f32>::from_euler_angles(EulerAnglesOrder::XYZ, Vector3::new(0.3, -0.7, 1.1));
+        let affine = Matrix4x4::<f32>::from_trs(Vector3::new(1.0, -2.0, 3.0), &rotation, Vector3::new(2.0, -3.0, 0.5));
+        let inverse = affine.try_inverse().unwrap();
+        assert!(close(affine * inverse, Matrix4x4::IDENTITY));
+        assert!(close(affine.affine_inverse().unwrap(), inverse));
+        let rigid = Matrix4x4::<f32>::from_trs(Vector3::new(1.0, -2.0, 3.0), &rotation, Vector3::ONE);
+        assert!(close(rigid.rigid_inverse(), rigid.try_inverse().unwrap()));
+        assert!((affine.determinant() - -3.0).abs() < 0.0001);
+    }
+    // Vector4<f32> and Matrix4x4<f32> may use the SIMD kernels, so compare them against the (always scalar) f64 versions
+    #[test]
+    pub fn simd_kernels() {
+        let close = |a: Vector4<f32>, b: Vector4<f64>| (0..4).all(|i| (a[i] as f64 - b[i]).abs() < 0.0001);
+        let a = Vector4::<f64>::new(0.5, -2.0, 3.25, 1.5);
+        let b = Vector4::<f64>::new(-1.5, 4.0, 0.75, -2.0);
+        assert!((Vector4::<f32>::from(a).dot(Vector4::from(b)) as f64 - a.dot(b)).abs() < 0.0001);
+        assert!((Vector4::<f32>::from(a).length() as f64 - a.length()).abs() < 0.0001);
+        assert!(close(Vector4::<f32>::from(a).normalized(), a.normalized()));
+        let mut normalized = Vector4::<f32>::from(b);
+        normalized.normalize();
+        assert!(close(normalized, b.normalized()));
+        assert_eq!(Vector4::<f32>::new(1.0, 2.0, 3.0, 4.0).dot(Vector4::ONE), 10.0);
+        let (single_a, single_b) = (Vector4::<f32>::from(a), Vector4::<f32>::from(b));
+        assert!(close(single_a + single_b, a + b));
+        assert!(close(single_a - single_b, a - b));
+        assert!(close(single_a * single_b, a * b));
+        assert!(close(single_a / single_b, a / b));
+        assert!(close(single_a + 1.5, a + 1.5));
+        assert!(close(single_a - 1.5, a - 1.5));
+        assert!(close(single_a * -3.0, a * -3.0));
+        assert!(close(single_a / 4.0, a / 4.0));
+        let mut assigned = single_a;
+        assigned += single_b;
+        assigned *= 2.0;
+        assigned -= single_a;
+        assigned /= single_b;
+        assert!(close(assigned, ((a + b) * 2.0 - a) / b));
+
+        let rotation = Quaternion::<f64>::from_euler_angles(EulerAnglesOrder::ZYX, Vector3::new(0.4, 1.2, -0.3));
+        let m1 = Matrix4x4::<f64>::from_trs(Vector3::new(3.0, -1.0, 2.0), &rotation, Vector3::new(1.5, 0.5, -2.0));
+        let m2 = Matrix4x4::<f64>::from_perspective(0.1, 100.0, 1.5, 70_f64.to_radians());
+        let m3 = Matrix4x4::<f64>::new(
+            Vector4::new(2.0, 1.0, 0.5, 0.25),
+            Vector4::new(-1.0, 3.0, 0.0, 1.0),
+            Vector4::new(0.0, -2.0, 4.0, 0.5),
+            Vector4::new(1.0, 0.0, -1.0, 2.0),
+        );
+        let single = |m: Matrix4x4<f64>| Matrix4x4::<f32> { data: m.data.map(Vector4::<f32>::from) };
+        let close_matrix = |a: Matrix4x4<f32>, b: Matrix4x4<f64>| (0..4).all(|i| close(a.data[i], b.data[i]));
+        for (a, b) in [(m1, m2), (m2, m3), (m3, m1)] {
+            assert!(close_matrix(single(a) * single(b), a * b));
+            assert!(close(single(a) * Vector4::from(a.data[3]), a * a.data[3]));
+            assert!((single(a).determinant() as f64 - a.determinant()).abs() < 0.001);
+            assert!(close_matrix(single(a).try_inverse().unwrap(), a.try_inverse().unwrap()));
+        }
+    }
+    // Serde round trips
+    #[cfg(feature = "serde")]
+    #[test]
+    pub fn serialization() {
+        let vector = Vector3::<f32>::new(1.0, -2.5, 3.0);
+        assert_eq!(serde_json::to_string(&vector).unwrap(), "[1.0,-2.5,3.0]");
+        assert_eq!(serde_json::from_str::<Vector3<f32>>("[1.0, -2.5, 3.0]").unwrap(), vector);
+        assert!(serde_json::from_str::<Vector3<f32>>("[1.0, 2.0]").is_err());
+        assert_eq!(serde_json::to_string(&Vector2::<i32>::new(4, 5)).unwrap(), "[4,5]");
+        assert_eq!(serde_json::to_string(&Vector4::<u8>::new(1, 2, 3, 4)).unwrap(), "[1,2,3,4]");
+
+        let quaternion = Quaternion::<f32>::from_xyzw(0.0, 0.6, 0.0, 0.8);
+        assert_eq!(serde_json::to_string(&quaternion).unwrap(), "[0.0,0.6,0.0,0.8]");
+        let matrix = Matrix4x4::<f32>::from_trs(Vector3::new(1.0, 2.0, 3.0), &quaternion, Vector3::ONE);
+        let json = serde_json::to_string(&matrix).unwrap();
+        assert!(json.starts_with("[[") && json.ends_with("[1.0,2.0,3.0,1.0]]"));
+        assert_eq!(serde_json::from_str::<Matrix4x4<f32>>(&json).unwrap(), matrix);
+        assert_eq!(serde_json::to_string(&Matrix2x2::<f64>::IDENTITY).unwrap(), "[[1.0,0.0],[0.0,1.0]]");
+
+        // RON
+        let ron = ron::to_string(&(vector, quaternion, matrix, Matrix3x3::<f64>::IDENTITY)).unwrap();
+        let (v, q, m, m3): (Vector3<f32>, Quaternion<f32>, Matrix4x4<f32>, Matrix3x3<f64>) = ron::from_str(&ron).unwrap();
+        assert_eq!((v, q, m, m3), (vector, quaternion, matrix, Matrix3x3::IDENTITY));
+    }
+    // Safe byte views using bytemuck
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    pub fn byte_views() {
+        use crate::{cast_slice, cast_slice_mut, AsBytes};
+        let vector = Vector3::<f32>::new(1.0, 2.0, 3.0);
+        assert_eq!(vector.as_bytes(), unsafe { vector.to_native_bytes() });
+        assert_eq!(vector.as_bytes()[4..8], 2.0_f32.to_ne_bytes());
+
+        // A vertex buffer
+        let mut vertices = vec![Vector3::<f32>::ZERO, Vector3::ONE, Vector3::new(4.0, 5.0, 6.0)];
+        assert_eq!(vertices.as_bytes().len(), 36);
+        assert_eq!(cast_slice::<Vector3<f32>, f32>(&vertices), &[0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 4.0, 5.0, 6.0]);
+        cast_slice_mut::<Vector3<f32>, f32>(&mut vertices)[0] = 7.0;
+        assert_eq!(vertices[0], Vector3::new(7.0, 0.0, 0.0));
+        assert_eq!(cast_slice::<f32, Vector3<f32>>(&[1.0, 2.0, 3.0]), &[vector]);
+
+        // Matrices are stored collumn after collumn
+        let matrix = Matrix4x4::<f32>::from_translation(Vector3::new(1.0, 2.0, 3.0));
+        assert_eq!(matrix.as_bytes().len(), 64);
+        assert_eq!(cast_slice::<Matrix4x4<f32>, f32>(&[matrix])[12..15], [1.0, 2.0, 3.0]);
+        let quaternion = Quaternion::<f32>::from_xyzw(0.0, 0.6, 0.0, 0.8);
+        assert_eq!(cast_slice::<Quaternion<f32>, f32>(&[quaternion]), &[0.0, 0.6, 0.0, 0.8]);
+        assert_eq!(<Matrix4x4<f64> as bytemuck::Zeroable>::zeroed(), Matrix4x4 { data: [Vector4::ZERO; 4] });
+        assert_eq!(Matrix3x3::<f32>::IDENTITY.as_bytes().len(), 36);
+        assert_eq!(Vector3x4::<f32>::ONE.as_bytes().len(), 48);
+    }
+    // std140 and std430 buffer layouts
+    #[test]
+    pub fn buffer_layouts() {
+        // The example uniform block
+        // float a; vec3 b; mat3 c; vec2 d; float e; mat2 f; vec4 g; float h[2];
+        let write = |layout: BufferLayout| {
+            let mut buffer = Vec::<u8>::new();
+            let offsets = [
+                1.0_f32.write_to_buffer(layout, &mut buffer),
+                Vector3::<f32>::new(2.0, 3.0, 4.0).write_to_buffer(layout, &mut buffer),
+                Matrix3x3::<f32>::IDENTITY.write_to_buffer(layout, &mut buffer),
+                Vector2::<f32>::ONE.write_to_buffer(layout, &mut buffer),
+                5.0_f32.write_to_buffer(layout, &mut buffer),
+                Matrix2x2::<f32>::IDENTITY.write_to_buffer(layout, &mut buffer),
+                Quaternion::<f32>::IDENTITY.write_to_buffer(layout, &mut buffer),
+                [6.0_f32, 7.0].write_to_buffer(layout, &mut buffer),
+            ];
+            (offsets, buffer)
+        };
+        let read = |buffer: &[u8], offset: usize| f32::from_ne_bytes(buffer[offset..offset + 4].try_into().unwrap());
+
+        let (offsets, buffer) = write(BufferLayout::Std140);
+        assert_eq!(offsets, [0, 16, 32, 80, 88, 96, 128, 144]);
+        assert_eq!(buffer.len(), 176);
+        assert_eq!(read(&buffer, 24), 4.0);
+        // The second collumn of the mat3 starts 16 bytes after the first one
+        assert_eq!(read(&buffer, 48 + 4), 1.0);
+        assert_eq!(read(&buffer, 48 + 12), 0.0);
+        // mat2 collumns are padded to 16 bytes in std140
+        assert_eq!(read(&buffer, 112 + 4), 1.0);
+        assert_eq!(read(&buffer, 128 + 12), 1.0);
+        assert_eq!(read(&buffer, 160), 7.0);
+
+        let (offsets, buffer) = write(BufferLayout::Std430);
+        assert_eq!(offsets, [0, 16, 32, 80, 88, 96, 112, 128]);
+        assert_eq!(buffer.len(), 136);
+        assert_eq!(read(&buffer, 104 + 4), 1.0);
+        assert_eq!(read(&buffer, 132), 7.0);
+        assert_eq!(Matrix4x4::<f32>::size(BufferLayout::Std430), 64);
+        assert_eq!(Vector3::<f64>::alignment(BufferLayout::Std140), 32);
+
+        // The padded types can be used directly inside #[repr(C)] structs
+        assert_eq!(std::mem::size_of::<Std140Vec3>(), 16);
+        assert_eq!(std::mem::align_of::<Std140Mat3>(), 16);
+        let matrix = Matrix3x3::<f32>::from_scale(Vector3::new(1.0, 2.0, 3.0));
+        let padded = Std140Mat3::from(matrix);
+        assert_eq!(padded.collumns[1].value, Vector3::new(0.0, 2.0, 0.0));
+        assert_eq!(Matrix3x3::from(padded), matrix);
+        assert_eq!(Vector3::from(Std140Vec3::from(Vector3::<f32>::ONE)), Vector3::ONE);
+    }
+    // Explicit endianness bytes
+    #[test]
+    pub fn endian_bytes() {
+        let vector = Vector3::<f32>::new(1.0, -2.0, 0.5);
+        let le = vector.to_le_bytes();
+        assert_eq!(le[4..8], (-2.0_f32).to_le_bytes());
+        assert_eq!(vector.to_be_bytes()[8..12], 0.5_f32.to_be_bytes());
+        assert_eq!(Vector3::<f32>::from_le_bytes(le), vector);
+        assert_eq!(Vector3::<f32>::from_be_bytes(vector.to_be_bytes()), vector);
+        assert_eq!(Vector2::<u16>::new(0x0102, 0x0304).to_be_bytes(), [1, 2, 3, 4]);
+        assert_eq!(Vector2::<u16>::new(0x0102, 0x0304).to_le_bytes(), [2, 1, 4, 3]);
+        assert_eq!(Vector4::<i64>::from_be_bytes(Vector4::<i64>::new(-1, 2, -3, 4).to_be_bytes()), Vector4::new(-1, 2, -3, 4));
+
+        // Matrices are encoded collumn after collumn
+        let matrix = Matrix4x4::<f64>::from_translation(Vector3::new(7.0, 8.0, 9.0));
+        let bytes = matrix.to_le_bytes();
+        assert_eq!(bytes.len(), 128);
+        assert_eq!(bytes[96..104], 7.0_f64.to_le_bytes());
+        assert_eq!(Matrix4x4::<f64>::from_le_bytes(bytes), matrix);
+        let quaternion = Quaternion::<f32>::from_xyzw(0.0, 0.6, 0.0, 0.8);
+        assert_eq!(quaternion.to_be_bytes()[12..16], 0.8_f32.to_be_bytes());
+        assert_eq!(Quaternion::<f32>::from_be_bytes(quaternion.to_be_bytes()), quaternion);
+
+        // Readers and writers
+        let mut buffer = Vec::new();
+        vector.write_to(&mut buffer).unwrap();
+        matrix.write_to(&mut buffer).unwrap();
+        quaternion.write_to(&mut buffer).unwrap();
+        assert_eq!(buffer.len(), 12 + 128 + 16);
+        let mut reader = buffer.as_slice();
+        assert_eq!(Vector3::<f32>::read_from(&mut reader).unwrap(), vector);
+        assert_eq!(Matrix4x4::<f64>::read_from(&mut reader).unwrap(), matrix);
+        assert_eq!(Quaternion::<f32>::read_from(&mut reader).unwrap(), quaternion);
+        let error = Vector3::<f32>::read_from(&mut reader).unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+    // mint conversions
+    #[cfg(feature = "mint")]
+    #[test]
+    pub fn mint_conversions() {
+        let vector: mint::Vector3<f32> = Vector3::new(1.0, 2.0, 3.0).into();
+        assert_eq!((vector.x, vector.y, vector.z), (1.0, 2.0, 3.0));
+        assert_eq!(Vector3::from(vector), Vector3::new(1.0, 2.0, 3.0));
+        assert_eq!(Vector2::from(mint::Vector2::from(Vector2::new(4, 5))), Vector2::new(4, 5));
+        assert_eq!(Vector4::from(mint::Vector4::from(Vector4::<u8>::ONE)), Vector4::ONE);
+
+        let quaternion = Quaternion::<f32>::from_xyzw(0.0, 0.6, 0.0, 0.8);
+        let mint_quaternion: mint::Quaternion<f32> = quaternion.into();
+        assert_eq!(mint_quaternion.s, 0.8);
+        assert_eq!(mint_quaternion.v.y, 0.6);
+        assert_eq!(Quaternion::from(mint_quaternion), quaternion);
+
+        // The collumns of a column major matrix are the rows of a row major matrix
+        let matrix = Matrix4x4::<f32>::from_translation(Vector3::new(7.0, 8.0, 9.0));
+        let collumns: mint::ColumnMatrix4<f32> = matrix.into();
+        assert_eq!(collumns.w, mint::Vector4 { x: 7.0, y: 8.0, z: 9.0, w: 1.0 });
+        let rows: mint::RowMatrix4<f32> = matrix.into();
+        assert_eq!(rows.x, mint::Vector4 { x: 1.0, y: 0.0, z: 0.0, w: 7.0 });
+        assert_eq!(Matrix4x4::from(collumns), matrix);
+        assert_eq!(Matrix4x4::from(rows), matrix);
+        let rotation = Matrix3x3::<f64>::from_quaternion(&Quaternion::<f64>::from_x_angle(0.5));
+        assert_eq!(Matrix3x3::from(mint::RowMatrix3::from(rotation)), rotation);
+        assert_eq!(mint::RowMatrix3::from(rotation).y.z, rotation.data[2].y);
+        assert_eq!(Matrix2x2::from(mint::ColumnMatrix2::from(Matrix2x2::<f32>::IDENTITY)), Matrix2x2::IDENTITY);
+    }
+    // glam conversions, and cross checks against glam
+    #[cfg(feature = "glam")]
+    #[test]
+    pub fn glam_conversions() {
+        let close = |a: Matrix4x4<f32>, b: glam::Mat4| (0..16).all(|i| (a[i] - b.to_cols_array()[i]).abs() < 0.0001);
+        assert_eq!(glam::Vec3::from(Vector3::new(1.0, 2.0, 3.0)), glam::Vec3::new(1.0, 2.0, 3.0));
+        assert_eq!(Vector4::from(glam::DVec4::new(1.0, 2.0, 3.0, 4.0)), Vector4::<f64>::new(1.0, 2.0, 3.0, 4.0));
+        assert_eq!(Vector2::from(glam::IVec2::new(-1, 2)), Vector2::new(-1, 2));
+        let quaternion = Quaternion::<f32>::from_xyzw(0.0, 0.6, 0.0, 0.8);
+        assert_eq!(glam::Quat::from(quaternion), glam::Quat::from_xyzw(0.0, 0.6, 0.0, 0.8));
+        assert_eq!(Quaternion::from(glam::Quat::from(quaternion)), quaternion);
+        let translation = Matrix4x4::<f32>::from_translation(Vector3::new(7.0, 8.0, 9.0));
+        assert_eq!(glam::Mat4::from(translation), glam::Mat4::from_translation(glam::Vec3::new(7.0, 8.0, 9.0)));
+        assert_eq!(Matrix4x4::from(glam::Mat4::from(translation)), translation);
+
+        // Cross checks
+        let eye = Vector3::<f32>::new(3.0, 2.0, -4.0);
+        let target = Vector3::<f32>::new(-1.0, 0.5, 2.0);
+        let up = Vector3::<f32>::new(0.2, 1.0, 0.1);
+        let look_at = glam::camera::rh::view::look_at_mat4(eye.into(), target.into(), up.into());
+        assert!(close(Matrix4x4::<f32>::look_at(&eye, &up, &target), look_at));
+        let perspective = Matrix4x4::<f32>::from_perspective(0.1, 500.0, 16.0 / 9.0, 70_f32.to_radians());
+        assert!(close(perspective, glam::camera::rh::proj::opengl::perspective(70_f32.to_radians(), 16.0 / 9.0, 0.1, 500.0)));
+        let rotation = Quaternion::<f32>::from_axis_angle(Vector3::<f32>::new(1.0, 2.0, -1.0).normalized(), 1.3);
+        let glam_rotation = glam::Quat::from_axis_angle(glam::Vec3::new(1.0, 2.0, -1.0).normalize(), 1.3);
+        assert!(glam::Quat::from(rotation).abs_diff_eq(glam_rotation, 0.0001));
+        assert!(glam::Vec3::from(rotation.mul_point(eye)).abs_diff_eq(glam_rotation * glam::Vec3::from(eye), 0.0001));
+        let transform = Matrix4x4::<f32>::from_trs(eye, &rotation, Vector3::new(2.0, 0.5, 1.5));
+        assert!(close(transform.try_inverse().unwrap(), glam::Mat4::from(transform).inverse()));
+        assert!(close(transform * perspective, glam::Mat4::from(transform) * glam::Mat4::from(perspective)));
+    }
+    // nalgebra conversions, and cross checks against nalgebra
+    #[cfg(feature = "nalgebra")]
+    #[test]
+    pub fn nalgebra_conversions() {
+        let close = |a: Matrix4x4<f64>, b: nalgebra::Matrix4<f64>| (0..16).all(|i| (a[i] - b.as_slice()[i]).abs() < 0.000001);
+        assert_eq!(nalgebra::Vector3::from(Vector3::new(1.0, 2.0, 3.0)), nalgebra::Vector3::new(1.0, 2.0, 3.0));
+        assert_eq!(Vector2::from(nalgebra::Vector2::new(1_u8, 2)), Vector2::new(1, 2));
+        assert_eq!(Vector4::from(nalgebra::Vector4::new(1.0, 2.0, 3.0, 4.0)), Vector4::<f32>::new(1.0, 2.0, 3.0, 4.0));
+        // nalgebra takes W first when creating quaternions
+        let quaternion = Quaternion::<f64>::from_xyzw(0.0, 0.6, 0.0, 0.8);
+        assert_eq!(nalgebra::Quaternion::from(quaternion), nalgebra::Quaternion::new(0.8, 0.0, 0.6, 0.0));
+        assert_eq!(Quaternion::from(nalgebra::Quaternion::from(quaternion)), quaternion);
+        let translation = Matrix4x4::<f64>::from_translation(Vector3::new(7.0, 8.0, 9.0));
+        let nalgebra_translation = nalgebra::Matrix4::new_translation(&nalgebra::Vector3::new(7.0, 8.0, 9.0));
+        assert_eq!(nalgebra::Matrix4::from(translation), nalgebra_translation);
+        assert_eq!(Matrix4x4::from(nalgebra::Matrix4::from(translation)), translation);
+
+        // Cross checks
+        let eye = Vector3::<f64>::new(3.0, 2.0, -4.0);
+        let target = Vector3::<f64>::new(-1.0, 0.5, 2.0);
+        let up = Vector3::<f64>::new(0.2, 1.0, 0.1);
+        let point = |v: Vector3<f64>| nalgebra::Point3::from(nalgebra::Vector3::from(v));
+        let look_at = nalgebra::Matrix4::look_at_rh(&point(eye), &point(target), &up.into());
+        assert!(close(Matrix4x4::<f64>::look_at(&eye, &up, &target), look_at));
+        let perspective = Matrix4x4::<f64>::from_perspective(0.1, 500.0, 16.0 / 9.0, 70_f64.to_radians());
+        let nalgebra_perspective = nalgebra::Perspective3::new(16.0 / 9.0, 70_f64.to_radians(), 0.1, 500.0);
+        assert!(close(perspective, nalgebra_perspective.to_homogeneous()));
+        let axis = Vector3::<f64>::new(1.0, 2.0, -1.0).normalized();
+        let rotation = Quaternion::<f64>::from_axis_angle(axis, 1.3);
+        let nalgebra_rotation = nalgebra::UnitQuaternion::from_axis_angle(&nalgebra::Unit::new_normalize(axis.into()), 1.3);
+        assert!((Vector4::from(nalgebra::Quaternion::from(rotation).coords) - Vector4::from(nalgebra_rotation.coords)).length() < 0.000001);
+        assert_eq!(Quaternion::from(nalgebra_rotation), Quaternion::from(nalgebra_rotation.into_inner()));
+        let transform = Matrix4x4::<f64>::from_trs(eye, &rotation, Vector3::new(2.0, 0.5, 1.5));
+        assert!(close(transform.try_inverse().unwrap(), nalgebra::Matrix4::from(transform).try_inverse().unwrap()));
+        assert!(close(transform * perspective, nalgebra::Matrix4::from(transform) * nalgebra::Matrix4::from(perspective)));
+    }
     // Quaternion tests
     #[test]
     pub fn quaternion() {
@@ -107,6 +750,336 @@ mod tests {
         println!("{:?}", Quaternion::<f32>::IDENTITY.mul_point(Vector3::<f32>::Y));
         //assert_eq!(quaternion.mul_point(Vector3::<f32>::new(1.0, 0.0, 0.0)), Vector3::default_z());
     }
+    // mul_quaternion used to add the dot product to the scalar part, which broke composed rotations
+    #[test]
+    pub fn quaternion_composition() {
+        let close = |a: Vector3<f32>, b: Vector3<f32>| (a - b).length() < 0.0001;
+        let yaw = Quaternion::<f32>::from_y_angle(90_f32.to_radians());
+        let pitch = Quaternion::<f32>::from_x_angle(90_f32.to_radians());
+        let composed = pitch.mul_quaternion(yaw);
+        assert!((composed[3] - 0.5).abs() < 0.0001);
+        // Rotating by the product is the same as rotating by "yaw" and then by "pitch"
+        for point in [Vector3::<f32>::X, Vector3::Y, Vector3::Z, Vector3::new(1.0, 2.0, 3.0)] {
+            assert!(close(composed.mul_point(point), pitch.mul_point(yaw.mul_point(point))));
+        }
+        // Two quarter turns around the same axis make a half turn
+        let half_turn = yaw.mul_quaternion(yaw);
+        assert!(close(half_turn.mul_point(Vector3::X), -Vector3::X));
+        assert!(close(half_turn.mul_point(Vector3::Y), Vector3::Y));
+    }
+    // Quaternion algebra
+    #[test]
+    pub fn quaternion_algebra() {
+        let quaternion = Quaternion::<f32>::from_xyzw(1.0, 2.0, 3.0, 4.0);
+        assert_eq!((quaternion.x(), quaternion.y(), quaternion.z(), quaternion.w()), (1.0, 2.0, 3.0, 4.0));
+        assert_eq!(quaternion.xyz(), Vector3::new(1.0, 2.0, 3.0));
+        assert_eq!(quaternion.conjugate(), Quaternion::from_xyzw(-1.0, -2.0, -3.0, 4.0));
+        assert_eq!(-quaternion, Quaternion::from_xyzw(-1.0, -2.0, -3.0, -4.0));
+        assert_eq!(quaternion + quaternion, quaternion * 2.0);
+        assert_eq!(quaternion - quaternion, Quaternion::from_xyzw(0.0, 0.0, 0.0, 0.0));
+        assert_eq!(quaternion.dot(Quaternion::IDENTITY), 4.0);
+        assert_eq!(Quaternion::<f32>::from_xyzw(0.0, 3.0, 0.0, 4.0).length(), 5.0);
+        assert_eq!(Quaternion::<f32>::from_xyzw(0.0, 3.0, 0.0, 4.0).normalized(), Quaternion::from_xyzw(0.0, 0.6, 0.0, 0.8));
+        assert_ne!(quaternion, Quaternion::IDENTITY);
+        let product = quaternion.mul_quaternion(quaternion.inverse());
+        assert!((product - Quaternion::IDENTITY).length() < 0.0001);
+
+        // Rotating points
+        let rotation = Quaternion::<f32>::from_z_angle(90_f32.to_radians());
+        assert!((rotation * Vector3::X - Vector3::Y).length() < 0.0001);
+        assert!((rotation.inverse() * (rotation * Vector3::new(1.0, 2.0, 3.0)) - Vector3::new(1.0, 2.0, 3.0)).length() < 0.0001);
+    }
+    // Quaternion interpolation
+    #[test]
+    pub fn quaternion_interpolation() {
+        let close = |a: Quaternion<f32>, b: Quaternion<f32>| (0..4).all(|i| (a[i] - b[i]).abs() < 0.0001);
+        let start = Quaternion::<f32>::IDENTITY;
+        let end = Quaternion::<f32>::from_y_angle(90_f32.to_radians());
+        let half = Quaternion::<f32>::from_y_angle(45_f32.to_radians());
+        assert!(close(start.slerp(end, 0.0), start));
+        assert!(close(start.slerp(end, 1.0), end));
+        assert!(close(start.slerp(end, 0.5), half));
+        assert!(close(start.slerp(end, 0.25), Quaternion::<f32>::from_y_angle(22.5_f32.to_radians())));
+        assert!(close(start.nlerp(end, 0.5), half));
+        // The negated quaternion represents the same rotation, so the shortest path must be taken
+        let mut negated = end;
+        for i in 0..4 {
+            negated[i] = -negated[i];
+        }
+        assert!(close(start.slerp(negated, 0.5), half));
+        assert!(close(start.nlerp(negated, 0.5), half));
+        // Composition
+        let composed = Quaternion::<f32>::from_y_angle(30_f32.to_radians()).mul_quaternion(Quaternion::<f32>::from_y_angle(60_f32.to_radians()));
+        assert!(close(composed, end));
+
+        // Evenly spaced keyframes around a single axis should follow the slerp path
+        let keys: Vec<Quaternion<f64>> = (0..4).map(|i| Quaternion::<f64>::from_x_angle((i as f64 * 30.0).to_radians())).collect();
+        let a = Quaternion::<f64>::squad_control_point(keys[0], keys[1], keys[2]);
+        let b = Quaternion::<f64>::squad_control_point(keys[1], keys[2], keys[3]);
+        for t in [0.0, 0.3, 0.5, 0.8, 1.0] {
+            let squad = keys[1].squad(keys[2], a, b, t);
+            let slerp = keys[1].slerp(keys[2], t);
+            assert!((0..4).all(|i| (squad[i] - slerp[i]).abs() < 0.000001));
+        }
+        let log = Quaternion::<f64>::from_z_angle(1.0).log();
+        assert!((log[2] - 0.5).abs() < 0.000001);
+        assert!((0..4).all(|i| (log.exp()[i] - Quaternion::<f64>::from_z_angle(1.0)[i]).abs() < 0.000001));
+    }
+    // Quaternion conversions
+    #[test]
+    pub fn quaternion_conversions() {
+        // Two quaternions represent the same rotation if they are equal or opposite
+        let same = |a: Quaternion<f64>, b: Quaternion<f64>| (0..4).all(|i| (a[i] - b[i]).abs() < 0.000001) || (0..4).all(|i| (a[i] + b[i]).abs() < 0.000001);
+        let orders = [
+            EulerAnglesOrder::XYZ,
+            EulerAnglesOrder::XZY,
+            EulerAnglesOrder::YXZ,
+            EulerAnglesOrder::YZX,
+            EulerAnglesOrder::ZXY,
+            EulerAnglesOrder::ZYX,
+        ];
+        for order in orders {
+            let euler = Vector3::<f64>::new(0.3, -0.7, 1.1);
+            let quaternion = Quaternion::<f64>::from_euler_angles(order, euler);
+            assert!((quaternion.to_euler_angles(order) - euler).length() < 0.000001, "{:?}", order);
+            // Gimbal lock, the angles themselves might differ but the rotation must stay the same
+            for middle in [90_f64.to_radians(), -90_f64.to_radians()] {
+                let mut euler = Vector3::<f64>::new(0.4, 0.4, 0.4);
+                let index = match order {
+                    EulerAnglesOrder::YXZ | EulerAnglesOrder::ZXY => 0,
+                    EulerAnglesOrder::XYZ | EulerAnglesOrder::ZYX => 1,
+                    EulerAnglesOrder::XZY | EulerAnglesOrder::YZX => 2,
+                };
+                euler[index] = middle;
+                let quaternion = Quaternion::<f64>::from_euler_angles(order, euler);
+                let back = Quaternion::<f64>::from_euler_angles(order, quaternion.to_euler_angles(order));
+                assert!(same(quaternion, back), "{:?}", order);
+            }
+        }
+
+        // Axis angle
+        let axis = Vector3::<f64>::new(1.0, 2.0, -2.0).normalized();
+        let (new_axis, angle) = Quaternion::<f64>::from_axis_angle(axis, 2.5).to_axis_angle();
+        assert!((new_axis - axis).length() < 0.000001);
+        assert!((angle - 2.5).abs() < 0.000001);
+        assert_eq!(Quaternion::<f64>::IDENTITY.to_axis_angle(), (Vector3::X, 0.0));
+
+        // Rotation matrices
+        let rotations = [
+            Quaternion::<f64>::from_euler_angles(EulerAnglesOrder::XYZ, Vector3::new(0.3, -0.7, 1.1)),
+            Quaternion::<f64>::from_x_angle(180_f64.to_radians()),
+            Quaternion::<f64>::from_y_angle(180_f64.to_radians()),
+            Quaternion::<f64>::from_z_angle(180_f64.to_radians()),
+            Quaternion::<f64>::from_axis_angle(axis, 3.0),
+        ];
+        for rotation in rotations {
+            assert!(same(Quaternion::<f64>::from_rotation_matrix(Matrix4x4::<f64>::from_quaternion(&rotation)), rotation));
+            assert!(same(Quaternion::<f64>::from_rotation_matrix(Matrix3x3::<f64>::from_quaternion(&rotation)), rotation));
+        }
+    }
+    // Quaternion from directions
+    #[test]
+    pub fn quaternion_directions() {
+        let pairs = [
+            (Vector3::<f32>::X, Vector3::<f32>::Y),
+            (Vector3::new(1.0, 2.0, 3.0), Vector3::new(-2.0, 0.5, 1.0)),
+            (Vector3::X, Vector3::X),
+            (Vector3::X, -Vector3::X),
+            (Vector3::new(0.3, -1.0, 0.2), -Vector3::new(0.3, -1.0, 0.2)),
+        ];
+        for (from, to) in pairs {
+            let rotation = Quaternion::<f32>::from_rotation_arc(from, to);
+            assert!((rotation.length() - 1.0).abs() < 0.0001);
+            assert!((rotation * from.normalized() - to.normalized()).length() < 0.0001, "{} {}", from, to);
+        }
+
+        // Must be consistent with the rotation part of the look at matrix
+        let eye = Vector3::<f64>::new(1.0, 2.0, 3.0);
+        let target = Vector3::<f64>::new(-4.0, 0.5, 2.0);
+        let rotation = Quaternion::<f64>::look_rotation(target - eye, Vector3::Y);
+        assert!((rotation * -Vector3::Z - (target - eye).normalized()).length() < 0.000001);
+        assert!((rotation * Vector3::Y).dot(Vector3::Y) > 0.0);
+        let view = Matrix3x3::<f64>::from(Matrix4x4::<f64>::look_at(&eye, &(rotation * Vector3::Y), &target));
+        let expected = Matrix3x3::<f64>::from_quaternion(&rotation.inverse());
+        for i in 0..9 {
+            assert!((view[i] - expected[i]).abs() < 0.000001);
+        }
+        // Degenerate up vector
+        let rotation = Quaternion::<f64>::look_rotation(Vector3::Y, Vector3::Y);
+        assert!((rotation * -Vector3::Z - Vector3::Y).length() < 0.000001);
+    }
+    // Axis aligned bounding boxes
+    #[test]
+    pub fn aabb() {
+        let aabb = Aabb::<f32>::from_points(&[Vector3::new(1.0, -2.0, 0.0), Vector3::new(-1.0, 4.0, 2.0), Vector3::new(0.0, 0.0, 1.0)]).unwrap();
+        assert_eq!(aabb, Aabb::new(Vector3::new(-1.0, -2.0, 0.0), Vector3::new(1.0, 4.0, 2.0)));
+        assert!(Aabb::<f32>::from_points(&[]).is_none());
+        assert_eq!(aabb.center(), Vector3::new(0.0, 1.0, 1.0));
+        assert_eq!(aabb.extents(), Vector3::new(1.0, 3.0, 1.0));
+        assert_eq!(aabb.volume(), 24.0);
+        assert_eq!(aabb.surface_area(), 2.0 * (12.0 + 4.0 + 12.0));
+        assert!(aabb.contains_point(&Vector3::new(1.0, 4.0, 2.0)));
+        assert!(!aabb.contains_point(&Vector3::new(1.0, 4.1, 2.0)));
+        assert_eq!(aabb.expand(1.0), Aabb::new(Vector3::new(-2.0, -3.0, -1.0), Vector3::new(2.0, 5.0, 3.0)));
+
+        let other = Aabb::<f32>::new(Vector3::ZERO, Vector3::ONE * 5.0);
+        assert!(aabb.intersects(&other));
+        assert_eq!(aabb.intersection(&other), Some(Aabb::new(Vector3::ZERO, Vector3::new(1.0, 4.0, 2.0))));
+        assert_eq!(aabb.union(&other), Aabb::new(Vector3::new(-1.0, -2.0, 0.0), Vector3::ONE * 5.0));
+        let far = Aabb::<f32>::new(Vector3::ONE * 10.0, Vector3::ONE * 11.0);
+        assert!(!aabb.intersects(&far));
+        assert_eq!(aabb.intersection(&far), None);
+
+        // Rotating a unit cube by 45 degrees around Y makes it wider on X and Z
+        let cube = Aabb::<f64>::new(-Vector3::ONE, Vector3::ONE);
+        let rotated = cube.transform(&Matrix4x4::<f64>::from_quaternion(&Quaternion::<f64>::from_y_angle(45_f64.to_radians())));
+        assert!((rotated.max - Vector3::new(2_f64.sqrt(), 1.0, 2_f64.sqrt())).length() < 0.000001);
+        let moved = cube.transform(&Matrix4x4::<f64>::from_translation(Vector3::X * 5.0));
+        assert_eq!(moved.center(), Vector3::X * 5.0);
+
+        let rect = Aabb2::<f32>::from_points(&[Vector2::ZERO, Vector2::new(2.0, 3.0)]).unwrap();
+        assert_eq!(rect.area(), 6.0);
+        assert_eq!(rect.perimeter(), 10.0);
+        assert_eq!(rect.transform(&Matrix3x3::<f32>::from_translation(Vector2::ONE)).min, Vector2::ONE);
+        assert!(rect.intersects(&Aabb2::new(Vector2::new(2.0, 3.0), Vector2::new(4.0, 4.0))));
+    }
+    // Ray intersections
+    #[test]
+    pub fn ray() {
+        let ray = Ray::<f32>::new(Vector3::new(0.0, 0.0, -10.0), Vector3::Z);
+        assert_eq!(ray.at(2.0), Vector3::new(0.0, 0.0, -8.0));
+
+        // AABB
+        let aabb = Aabb::<f32>::new(-Vector3::ONE, Vector3::ONE);
+        let hit = ray.intersect_aabb(&aabb).unwrap();
+        assert_eq!(hit.distance, 9.0);
+        assert_eq!(hit.normal, -Vector3::Z);
+        let inside = Ray::<f32>::new(Vector3::ZERO, Vector3::X).intersect_aabb(&aabb).unwrap();
+        assert_eq!((inside.distance, inside.normal), (1.0, Vector3::X));
+        assert!(Ray::<f32>::new(Vector3::new(0.0, 2.0, -10.0), Vector3::Z).intersect_aabb(&aabb).is_none());
+        assert!(Ray::<f32>::new(Vector3::new(0.0, 0.0, 10.0), Vector3::Z).intersect_aabb(&aabb).is_none());
+        let still = Ray::<f32>::new(Vector3::new(0.5, 0.0, -0.5), Vector3::ZERO).intersect_aabb(&aabb).unwrap();
+        assert_eq!((still.distance, still.normal), (0.0, Vector3::ZERO));
+        assert!(Ray::<f32>::new(Vector3::new(0.0, 2.0, 0.0), Vector3::ZERO).intersect_aabb(&aabb).is_none());
+        let diagonal = Ray::<f32>::new(Vector3::new(-5.0, 0.5, 0.0), Vector3::new(1.0, 0.1, 0.0)).intersect_aabb(&aabb).unwrap();
+        assert_eq!(diagonal.normal, -Vector3::X);
+        assert!((diagonal.distance - 4.0).abs() < 0.0001);
+
+        // Sphere
+        let hit = ray.intersect_sphere(&Sphere::new(Vector3::ZERO, 2.0)).unwrap();
+        assert_eq!((hit.distance, hit.normal), (8.0, -Vector3::Z));
+        let hit = Ray::<f32>::new(Vector3::ZERO, Vector3::Y * 2.0).intersect_sphere(&Sphere::new(Vector3::ZERO, 2.0)).unwrap();
+        assert_eq!((hit.distance, hit.normal), (1.0, Vector3::Y));
+        assert!(ray.intersect_sphere(&Sphere::new(Vector3::new(3.0, 0.0, 0.0), 2.0)).is_none());
+        assert!(ray.intersect_sphere(&Sphere::new(Vector3::new(0.0, 0.0, -20.0), 2.0)).is_none());
+
+        // Plane
+        let hit = ray.intersect_plane(&Plane::new(Vector3::Z, 5.0)).unwrap();
+        assert_eq!((hit.distance, hit.normal), (15.0, Vector3::Z));
+        assert!(ray.intersect_plane(&Plane::new(Vector3::Z, -15.0)).is_none());
+        assert!(ray.intersect_plane(&Plane::new(Vector3::X, 0.0)).is_none());
+
+        // Triangle
+        let (a, b, c) = (Vector3::<f64>::new(-1.0, -1.0, 0.0), Vector3::new(1.0, -1.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+        let ray = Ray::<f64>::new(Vector3::new(0.0, 0.0, 5.0), -Vector3::Z);
+        let hit = ray.intersect_triangle(a, b, c).unwrap();
+        assert_eq!((hit.distance, hit.normal), (5.0, Vector3::Z));
+        assert_eq!(ray.intersect_triangle(a, c, b).unwrap().normal, -Vector3::Z);
+        assert!(Ray::<f64>::new(Vector3::new(2.0, 0.0, 5.0), -Vector3::Z).intersect_triangle(a, b, c).is_none());
+        assert!(Ray::<f64>::new(Vector3::new(0.0, 0.0, 5.0), Vector3::Z).intersect_triangle(a, b, c).is_none());
+    }
+    // Planes and frustum culling
+    #[test]
+    pub fn frustum() {
+        let plane = Plane::<f32>::from_points(Vector3::new(0.0, 2.0, 0.0), Vector3::new(0.0, 2.0, 1.0), Vector3::new(1.0, 2.0, 0.0));
+        assert_eq!(plane, Plane::new(Vector3::Y, 2.0));
+        assert_eq!(plane.signed_distance(&Vector3::new(5.0, 5.0, 5.0)), 3.0);
+        assert_eq!(plane.signed_distance(&Vector3::ZERO), -2.0);
+        assert_eq!(plane.project_point(&Vector3::new(5.0, 5.0, 5.0)), Vector3::new(5.0, 2.0, 5.0));
+        let mut scaled = Plane::<f32>::new(Vector3::Y * 4.0, 8.0);
+        assert_eq!(scaled.project_point(&Vector3::ONE), Vector3::new(1.0, 2.0, 1.0));
+        scaled.normalize();
+        assert_eq!(scaled, plane);
+        assert_eq!(Plane::<f32>::from_normal_and_point(Vector3::Y, Vector3::new(3.0, 2.0, 1.0)), plane);
+
+        // Camera at (0, 0, 5) looking at the origin
+        let projection = Matrix4x4::<f32>::from_perspective(0.1, 100.0, 1.0, 90_f32.to_radians());
+        let view = Matrix4x4::<f32>::look_at(&Vector3::new(0.0, 0.0, 5.0), &Vector3::Y, &Vector3::ZERO);
+        let frustum = Frustum::<f32>::from_matrix(&(projection * view));
+        assert_eq!(frustum.test_point(&Vector3::ZERO), Containment::Inside);
+        assert_eq!(frustum.test_point(&Vector3::new(0.0, 0.0, 10.0)), Containment::Outside);
+        assert_eq!(frustum.test_point(&Vector3::new(0.0, 0.0, -200.0)), Containment::Outside);
+        assert_eq!(frustum.test_point(&Vector3::new(4.0, 0.0, 0.0)), Containment::Inside);
+        assert_eq!(frustum.test_point(&Vector3::new(6.0, 0.0, 0.0)), Containment::Outside);
+        assert_eq!(frustum.test_point(&Vector3::new(0.0, -6.0, 0.0)), Containment::Outside);
+
+        assert_eq!(frustum.test_sphere(&Sphere::new(Vector3::ZERO, 1.0)), Containment::Inside);
+        assert_eq!(frustum.test_sphere(&Sphere::new(Vector3::new(5.0, 0.0, 0.0), 1.0)), Containment::Intersecting);
+        assert_eq!(frustum.test_sphere(&Sphere::new(Vector3::new(0.0, 0.0, 8.0), 1.0)), Containment::Outside);
+
+        assert_eq!(frustum.test_aabb(&Aabb::new(-Vector3::ONE, Vector3::ONE)), Containment::Inside);
+        assert_eq!(frustum.test_aabb(&Aabb::new(Vector3::new(4.0, -1.0, -1.0), Vector3::new(6.0, 1.0, 1.0))), Containment::Intersecting);
+        assert_eq!(frustum.test_aabb(&Aabb::new(Vector3::new(-1.0, -1.0, 6.0), Vector3::new(1.0, 1.0, 7.0))), Containment::Outside);
+        assert_eq!(frustum.test_aabb(&Aabb::new(Vector3::new(-1.0, -1.0, 4.0), Vector3::new(1.0, 1.0, 7.0))), Containment::Intersecting);
+    }
+    // Spheres, capsules and OBBs
+    #[test]
+    pub fn bounding_volumes() {
+        // Bounding sphere
+        let points = [Vector3::<f32>::X, -Vector3::X, Vector3::Y, -Vector3::Y, Vector3::Z, -Vector3::Z, Vector3::new(0.5, 0.5, 0.5)];
+        let sphere = Sphere::<f32>::from_points(&points).unwrap();
+        assert!(points.iter().all(|point| sphere.center.distance(*point) <= sphere.radius + 0.0001));
+        assert!(sphere.radius < 1.1);
+        assert!(Sphere::<f32>::from_points(&[]).is_none());
+        assert_eq!(Sphere::<f32>::from_points(&[Vector3::ONE]), Some(Sphere::new(Vector3::ONE, 0.0)));
+
+        // Closest points
+        let sphere = Sphere::<f32>::new(Vector3::ZERO, 2.0);
+        assert_eq!(sphere.closest_point(&(Vector3::X * 5.0)), Vector3::X * 2.0);
+        assert_eq!(sphere.closest_point(&Vector3::X), Vector3::X);
+        let capsule = Capsule::<f32>::new(Vector3::ZERO, Vector3::Y * 4.0, 1.0);
+        assert_eq!(capsule.closest_point(&Vector3::new(3.0, 2.0, 0.0)), Vector3::new(1.0, 2.0, 0.0));
+        assert_eq!(capsule.closest_point(&(Vector3::Y * 10.0)), Vector3::Y * 5.0);
+        assert!(capsule.contains_point(&Vector3::new(0.5, -0.5, 0.0)));
+        let obb = Obb::<f32>::new(Vector3::ZERO, Vector3::new(2.0, 1.0, 1.0), Quaternion::<f32>::from_z_angle(90_f32.to_radians()));
+        assert!((obb.closest_point(&(Vector3::Y * 5.0)) - Vector3::Y * 2.0).length() < 0.0001);
+        assert!((obb.closest_point(&(Vector3::X * 5.0)) - Vector3::X).length() < 0.0001);
+        assert!(obb.contains_point(&Vector3::new(0.0, 1.5, 0.0)));
+        assert!(!obb.contains_point(&Vector3::new(1.5, 0.0, 0.0)));
+        assert_eq!(Obb::<f32>::from_aabb(&Aabb::new(Vector3::ZERO, Vector3::ONE * 2.0)).center, Vector3::ONE);
+
+        // Sphere pairs
+        assert!(sphere.intersects_sphere(&Sphere::new(Vector3::X * 3.0, 1.5)));
+        assert!(!sphere.intersects_sphere(&Sphere::new(Vector3::X * 3.0, 0.5)));
+        assert!(sphere.intersects_capsule(&Capsule::new(Vector3::new(2.5, -5.0, 0.0), Vector3::new(2.5, 5.0, 0.0), 1.0)));
+        assert!(!sphere.intersects_capsule(&Capsule::new(Vector3::new(3.5, -5.0, 0.0), Vector3::new(3.5, 5.0, 0.0), 1.0)));
+        assert!(Sphere::<f32>::new(Vector3::Y * 2.5, 0.6).intersects_obb(&obb));
+        assert!(!Sphere::<f32>::new(Vector3::X * 2.5, 0.6).intersects_obb(&obb));
+
+        // Capsule pairs
+        let crossing = Capsule::<f32>::new(Vector3::new(-5.0, 2.0, 1.5), Vector3::new(5.0, 2.0, 1.5), 0.6);
+        assert!(capsule.intersects_capsule(&crossing));
+        assert!(!capsule.intersects_capsule(&Capsule::new(Vector3::new(-5.0, 2.0, 1.5), Vector3::new(5.0, 2.0, 1.5), 0.4)));
+        assert!(capsule.intersects_capsule(&Capsule::new(Vector3::new(1.5, 0.0, 0.0), Vector3::new(1.5, 4.0, 0.0), 0.6)));
+        assert!(Capsule::<f32>::new(Vector3::new(-5.0, 2.5, 0.0), Vector3::new(5.0, 2.5, 0.0), 0.6).intersects_obb(&obb));
+        assert!(!Capsule::<f32>::new(Vector3::new(-5.0, 2.5, 0.0), Vector3::new(5.0, 2.5, 0.0), 0.4).intersects_obb(&obb));
+        assert!(Capsule::<f32>::new(Vector3::new(0.0, 0.0, -5.0), Vector3::new(0.0, 0.0, 5.0), 0.1).intersects_obb(&obb));
+        assert!(obb.intersects_capsule(&Capsule::new(Vector3::new(1.5, 0.0, -5.0), Vector3::new(1.5, 0.0, 5.0), 0.6)));
+        // The closest point is in the middle of the segment, right next to an edge of the box, at a distance of sqrt(0.5)
+        let edge = Obb::<f32>::new(Vector3::new(10.0, 0.0, 0.0), Vector3::ONE, Quaternion::<f32>::from_y_angle(30_f32.to_radians()));
+        let (start, end) = (edge.to_world(&Vector3::new(2.5, 0.5, -3.0)), edge.to_world(&Vector3::new(0.5, 2.5, 3.0)));
+        assert!(Capsule::<f32>::new(start, end, 0.72).intersects_obb(&edge));
+        assert!(!Capsule::<f32>::new(start, end, 0.69).intersects_obb(&edge));
+
+        // OBB pairs
+        let rotated = Obb::<f32>::new(Vector3::new(2.3, 0.0, 0.0), Vector3::ONE, Quaternion::<f32>::from_z_angle(45_f32.to_radians()));
+        assert!(obb.intersects_obb(&rotated));
+        assert!(!obb.intersects_obb(&Obb::new(Vector3::new(2.5, 0.0, 0.0), Vector3::ONE, rotated.orientation)));
+        let moved = Obb::<f32>::new(Vector3::new(2.5, 2.5, 0.0), Vector3::ONE, Quaternion::<f32>::from_z_angle(45_f32.to_radians()));
+        assert!(!obb.intersects_obb(&moved));
+        assert!(obb.intersects_obb(&obb));
+        assert!(!obb.intersects_obb(&Obb::new(Vector3::X * 1.5, Vector3::ONE * 0.4, Quaternion::IDENTITY)));
+    }
     // Vector axis
     #[test]
     pub fn vector_axis() {
@@ -1,6 +1,30 @@
-use std::{mem::size_of, slice};
+use std::{error::Error, fmt, mem::size_of, slice};
 use crate::{vectors::*};
 
+// The error returned when trying to access an element that does not exist
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndexOutOfBounds {
+    pub index: usize,
+    pub len: usize,
+    // The name of the type that got indexed, like "Vector3"
+    pub type_name: &'static str,
+}
+
+impl IndexOutOfBounds {
+    // Create a new error
+    pub const fn new(index: usize, len: usize, type_name: &'static str) -> Self {
+        Self { index, len, type_name }
+    }
+}
+
+impl fmt::Display for IndexOutOfBounds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "index out of bounds: the len of {} is {} but the index is {}", self.type_name, self.len, self.index)
+    }
+}
+
+impl Error for IndexOutOfBounds {}
+
 // The swizzable trait
 pub trait Swizzable<T> {
     // Get vector 4
@@ -9,6 +33,45 @@ pub trait Swizzable<T> {
     fn get3(&self, order: [usize; 3]) -> Vector3<T>;
     // Get vector 2
     fn get2(&self, order: [usize; 2]) -> Vector2<T>;
+    // Get vector 4, or an error if one of the indices is out of bounds
+    fn try_get4(&self, order: [usize; 4]) -> Result<Vector4<T>, IndexOutOfBounds>
+    where
+        Self: VectorElemCount,
+    {
+        check_indices::<Self>(&order)?;
+        Ok(self.get4(order))
+    }
+    // Get vector 3, or an error if one of the indices is out of bounds
+    fn try_get3(&self, order: [usize; 3]) -> Result<Vector3<T>, IndexOutOfBounds>
+    where
+        Self: VectorElemCount,
+    {
+        check_indices::<Self>(&order)?;
+        Ok(self.get3(order))
+    }
+    // Get vector 2, or an error if one of the indices is out of bounds
+    fn try_get2(&self, order: [usize; 2]) -> Result<Vector2<T>, IndexOutOfBounds>
+    where
+        Self: VectorElemCount,
+    {
+        check_indices::<Self>(&order)?;
+        Ok(self.get2(order))
+    }
+}
+
+// Make sure that all the indices are smaller than the element count of the vector
+fn check_indices<V: VectorElemCount + ?Sized>(indices: &[usize]) -> Result<(), IndexOutOfBounds> {
+    match indices.iter().find(|&&index| index >= V::ELEM_COUNT) {
+        Some(&index) => Err(IndexOutOfBounds::new(index, V::ELEM_COUNT, short_type_name::<V>())),
+        None => Ok(()),
+    }
+}
+
+// Get the name of a type without its path and generic parameters, like "Vector3"
+fn short_type_name<V: ?Sized>() -> &'static str {
+    let name = std::any::type_name::<V>();
+    let name = name.split('<').next().unwrap_or(name);
+    name.rsplit("::").next().unwrap_or(name)
 }
 
 pub trait VectorElemCount {
@@ -21,7 +84,11 @@ pub trait Vector<T> {
     // Get the pointer of this vector
     fn as_ptr(&self) -> *const T;
     fn as_ptr_mut(&mut self) -> *mut T;
-    // Read the bytes of this vector using native endianness
+    /// Read the bytes of this vector using native endianness
+    ///
+    /// # Safety
+    ///
+    /// The vector must be `#[repr(C)]` and made of plain old data
     unsafe fn to_native_bytes(&self) -> &[u8] 
     where 
         Self: Sized
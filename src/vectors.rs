@@ -1,6 +1,10 @@
 mod vec2;
 mod vec3;
+mod vec3xn;
 mod vec4;
+mod vecn;
 pub use self::vec2::*;
 pub use self::vec3::*;
+pub use self::vec3xn::*;
 pub use self::vec4::*;
+pub use self::vecn::*;
@@ -1,12 +1,12 @@
 use super::{Vector3, Vector4};
 use crate::{
     types::SupportedValue,
-    vector::{Swizzable, Vector, VectorElemCount},
+    vector::{IndexOutOfBounds, Swizzable, Vector, VectorElemCount},
 };
 use std::{
     fmt,
     hash::Hash,
-    ops::{Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Not, Sub, SubAssign},
+    ops::{Add, AddAssign, BitAnd, BitOr, BitXor, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Not, Sub, SubAssign},
 };
 
 // A simple 2D vector, no simd support what-so-ever
@@ -78,10 +78,9 @@ impl<T> Index<usize> for Vector2<T> {
     type Output = T;
     // Index
     fn index(&self, index: usize) -> &Self::Output {
-        match index {
-            0 => &self.x,
-            1 => &self.y,
-            _ => todo!(),
+        match self.get(index) {
+            Some(value) => value,
+            None => panic!("{}", IndexOutOfBounds::new(index, 2, "Vector2")),
         }
     }
 }
@@ -90,10 +89,29 @@ impl<T> Index<usize> for Vector2<T> {
 impl<T> IndexMut<usize> for Vector2<T> {
     // Mut index
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match self.get_mut(index) {
+            Some(value) => value,
+            None => panic!("{}", IndexOutOfBounds::new(index, 2, "Vector2")),
+        }
+    }
+}
+
+// Non panicking indexer
+impl<T> Vector2<T> {
+    // Get an element, or None if the index is out of bounds
+    pub fn get(&self, index: usize) -> Option<&T> {
         match index {
-            0 => &mut self.x,
-            1 => &mut self.y,
-            _ => todo!(),
+            0 => Some(&self.x),
+            1 => Some(&self.y),
+            _ => None,
+        }
+    }
+    // Get an element mutably, or None if the index is out of bounds
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        match index {
+            0 => Some(&mut self.x),
+            1 => Some(&mut self.y),
+            _ => None,
         }
     }
 }
@@ -149,30 +167,17 @@ crate::impl_eq_hash!(Vector2<u64>);
 crate::impl_eq_hash!(Vector2<u128>);
 
 // Run the macros
-crate::setup_add!(Vector2<T>, T);
-crate::setup_sub!(Vector2<T>, T);
-crate::setup_mul!(Vector2<T>, T);
-crate::setup_div!(Vector2<T>, T);
-crate::setup_neg!(Vector2<T>, T);
-
-crate::setup_any_vector_operations!(Vector2<u8>, T, u8);
-crate::setup_any_vector_operations!(Vector2<u16>, T, u16);
-crate::setup_any_vector_operations!(Vector2<u32>, T, u32);
-crate::setup_any_vector_operations!(Vector2<u64>, T, u64);
-crate::setup_any_vector_operations!(Vector2<u128>, T, u128);
-crate::setup_any_vector_operations!(Vector2<usize>, T, usize);
-crate::setup_any_vector_operations!(Vector2<i8>, T, i8);
-crate::setup_any_vector_operations!(Vector2<i16>, T, i16);
-crate::setup_any_vector_operations!(Vector2<i32>, T, i32);
-crate::setup_any_vector_operations!(Vector2<i64>, T, i64);
-crate::setup_any_vector_operations!(Vector2<i128>, T, i128);
-crate::setup_any_vector_operations!(Vector2<isize>, T, isize);
-crate::setup_any_vector_operations!(Vector2<f32>, T, f32);
-crate::setup_any_vector_operations!(Vector2<f64>, T, f64);
-
-crate::setup_floating_vector_operations!(Vector2<f32>, T, f32);
-crate::setup_floating_vector_operations!(Vector2<f64>, T, f64);
-crate::impl_elem_wise_comparison!(Vector2<T>, T, Vector2<bool>);
+crate::setup_vector_operator!(Vector2<T>, Add, add, AddAssign, add_assign, +);
+crate::setup_vector_operator!(Vector2<T>, Sub, sub, SubAssign, sub_assign, -);
+crate::setup_vector_operator!(Vector2<T>, Mul, mul, MulAssign, mul_assign, *);
+crate::setup_vector_operator!(Vector2<T>, Div, div, DivAssign, div_assign, /);
+crate::setup_neg!(Vector2<T>);
+
+crate::setup_any_vector_operations!(Vector2<T>);
+
+crate::setup_floating_vector_operations!(Vector2<f32>, f32);
+crate::setup_floating_vector_operations!(Vector2<f64>, f64);
+crate::impl_elem_wise_comparison!(Vector2<T>, Vector2<bool>);
 
 // Dear lord
 // I deeply apologize for this
@@ -195,3 +200,25 @@ crate::impl_from_vec2!(Vector2<u64>, u64, i8, i16, i32, i64, i128, u8, u16, u32,
 crate::impl_from_vec2!(Vector2<u128>, u128, i8, i16, i32, i64, i128, u8, u16, u32, u64, f32, f64, usize, bool, isize);
 crate::impl_from_vec2!(Vector2<isize>, isize, i8, i16, i32, i64, i128, u8, u16, u32, u64, u128, f32, f64, bool, usize);
 crate::impl_from_vec2!(Vector2<usize>, usize, i8, i16, i32, i64, i128, u8, u16, u32, u64, u128, f32, f64, bool, isize);
+
+// Explicit endianness bytes
+// usize and isize are left out since their size depends on the platform, and bool since it has no byte encoding of its own
+crate::impl_endian_bytes!(Vector2, 2, f32, f64, i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+
+// Swizzles
+crate::impl_swizzle!(
+    Vector2<T>,
+    Vector2,
+    [xx, x, x], [xy, x, y], [yx, y, x], [yy, y, y]
+);
+crate::impl_swizzle!(
+    Vector2<T>,
+    Vector3,
+    [xxx, x, x, x], [xxy, x, x, y], [xyx, x, y, x], [xyy, x, y, y], [yxx, y, x, x], [yxy, y, x, y], [yyx, y, y, x], [yyy, y, y, y]
+);
+crate::impl_swizzle!(
+    Vector2<T>,
+    Vector4,
+    [xxxx, x, x, x, x], [xxxy, x, x, x, y], [xxyx, x, x, y, x], [xxyy, x, x, y, y], [xyxx, x, y, x, x], [xyxy, x, y, x, y], [xyyx, x, y, y, x], [xyyy, x, y, y, y],
+    [yxxx, y, x, x, x], [yxxy, y, x, x, y], [yxyx, y, x, y, x], [yxyy, y, x, y, y], [yyxx, y, y, x, x], [yyxy, y, y, x, y], [yyyx, y, y, y, x], [yyyy, y, y, y, y]
+);
@@ -1,12 +1,13 @@
 use super::{Vector2, Vector4};
 use crate::{
     types::SupportedValue,
-    vector::{Swizzable, Vector}, VectorElemCount,
+    vector::{IndexOutOfBounds, Swizzable, Vector},
+    VectorElemCount,
 };
 use core::fmt;
 use std::{
     hash::Hash,
-    ops::{Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Not, Sub, SubAssign},
+    ops::{Add, AddAssign, BitAnd, BitOr, BitXor, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Not, Sub, SubAssign},
 };
 
 // A simple 3D vector, no simd support what-so-ever
@@ -102,11 +103,9 @@ impl<T> Index<usize> for Vector3<T> {
     type Output = T;
     // Index
     fn index(&self, index: usize) -> &Self::Output {
-        match index {
-            0 => &self.x,
-            1 => &self.y,
-            2 => &self.z,
-            _ => todo!(),
+        match self.get(index) {
+            Some(value) => value,
+            None => panic!("{}", IndexOutOfBounds::new(index, 3, "Vector3")),
         }
     }
 }
@@ -115,11 +114,31 @@ impl<T> Index<usize> for Vector3<T> {
 impl<T> IndexMut<usize> for Vector3<T> {
     // Mut index
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match self.get_mut(index) {
+            Some(value) => value,
+            None => panic!("{}", IndexOutOfBounds::new(index, 3, "Vector3")),
+        }
+    }
+}
+
+// Non panicking indexer
+impl<T> Vector3<T> {
+    // Get an element, or None if the index is out of bounds
+    pub fn get(&self, index: usize) -> Option<&T> {
         match index {
-            0 => &mut self.x,
-            1 => &mut self.y,
-            2 => &mut self.z,
-            _ => todo!(),
+            0 => Some(&self.x),
+            1 => Some(&self.y),
+            2 => Some(&self.z),
+            _ => None,
+        }
+    }
+    // Get an element mutably, or None if the index is out of bounds
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        match index {
+            0 => Some(&mut self.x),
+            1 => Some(&mut self.y),
+            2 => Some(&mut self.z),
+            _ => None,
         }
     }
 }
@@ -182,30 +201,17 @@ crate::impl_eq_hash!(Vector3<usize>);
 crate::impl_eq_hash!(Vector3<isize>);
 
 // Run the macros
-crate::setup_add!(Vector3<T>, T);
-crate::setup_sub!(Vector3<T>, T);
-crate::setup_mul!(Vector3<T>, T);
-crate::setup_div!(Vector3<T>, T);
-crate::setup_neg!(Vector3<T>, T);
-
-crate::setup_any_vector_operations!(Vector3<u8>, T, u8);
-crate::setup_any_vector_operations!(Vector3<u16>, T, u16);
-crate::setup_any_vector_operations!(Vector3<u32>, T, u32);
-crate::setup_any_vector_operations!(Vector3<u64>, T, u64);
-crate::setup_any_vector_operations!(Vector3<u128>, T, u128);
-crate::setup_any_vector_operations!(Vector3<i8>, T, i8);
-crate::setup_any_vector_operations!(Vector3<i16>, T, i16);
-crate::setup_any_vector_operations!(Vector3<i32>, T, i32);
-crate::setup_any_vector_operations!(Vector3<i64>, T, i64);
-crate::setup_any_vector_operations!(Vector3<i128>, T, i128);
-crate::setup_any_vector_operations!(Vector3<f32>, T, f32);
-crate::setup_any_vector_operations!(Vector3<f64>, T, f64);
-crate::setup_any_vector_operations!(Vector3<usize>, T, usize);
-crate::setup_any_vector_operations!(Vector3<isize>, T, isize);
-
-crate::setup_floating_vector_operations!(Vector3<f32>, T, f32);
-crate::setup_floating_vector_operations!(Vector3<f64>, T, f64);
-crate::impl_elem_wise_comparison!(Vector3<T>, T, Vector3<bool>);
+crate::setup_vector_operator!(Vector3<T>, Add, add, AddAssign, add_assign, +);
+crate::setup_vector_operator!(Vector3<T>, Sub, sub, SubAssign, sub_assign, -);
+crate::setup_vector_operator!(Vector3<T>, Mul, mul, MulAssign, mul_assign, *);
+crate::setup_vector_operator!(Vector3<T>, Div, div, DivAssign, div_assign, /);
+crate::setup_neg!(Vector3<T>);
+
+crate::setup_any_vector_operations!(Vector3<T>);
+
+crate::setup_floating_vector_operations!(Vector3<f32>, f32);
+crate::setup_floating_vector_operations!(Vector3<f64>, f64);
+crate::impl_elem_wise_comparison!(Vector3<T>, Vector3<bool>);
 
 // Dear lord
 // I deeply apologize for this
@@ -246,3 +252,36 @@ impl Vector3<f64> {
         Vector3::new(a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0])
     }
 }
+
+// Explicit endianness bytes
+// usize and isize are left out since their size depends on the platform, and bool since it has no byte encoding of its own
+crate::impl_endian_bytes!(Vector3, 3, f32, f64, i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+
+// Swizzles
+crate::impl_swizzle!(
+    Vector3<T>,
+    Vector2,
+    [xx, x, x], [xy, x, y], [xz, x, z], [yx, y, x], [yy, y, y], [yz, y, z], [zx, z, x], [zy, z, y], [zz, z, z]
+);
+crate::impl_swizzle!(
+    Vector3<T>,
+    Vector3,
+    [xxx, x, x, x], [xxy, x, x, y], [xxz, x, x, z], [xyx, x, y, x], [xyy, x, y, y], [xyz, x, y, z], [xzx, x, z, x], [xzy, x, z, y], [xzz, x, z, z], [yxx, y, x, x], [yxy, y, x, y],
+    [yxz, y, x, z], [yyx, y, y, x], [yyy, y, y, y], [yyz, y, y, z], [yzx, y, z, x], [yzy, y, z, y], [yzz, y, z, z], [zxx, z, x, x], [zxy, z, x, y], [zxz, z, x, z], [zyx, z, y, x],
+    [zyy, z, y, y], [zyz, z, y, z], [zzx, z, z, x], [zzy, z, z, y], [zzz, z, z, z]
+);
+crate::impl_swizzle!(
+    Vector3<T>,
+    Vector4,
+    [xxxx, x, x, x, x], [xxxy, x, x, x, y], [xxxz, x, x, x, z], [xxyx, x, x, y, x], [xxyy, x, x, y, y], [xxyz, x, x, y, z], [xxzx, x, x, z, x], [xxzy, x, x, z, y],
+    [xxzz, x, x, z, z], [xyxx, x, y, x, x], [xyxy, x, y, x, y], [xyxz, x, y, x, z], [xyyx, x, y, y, x], [xyyy, x, y, y, y], [xyyz, x, y, y, z], [xyzx, x, y, z, x],
+    [xyzy, x, y, z, y], [xyzz, x, y, z, z], [xzxx, x, z, x, x], [xzxy, x, z, x, y], [xzxz, x, z, x, z], [xzyx, x, z, y, x], [xzyy, x, z, y, y], [xzyz, x, z, y, z],
+    [xzzx, x, z, z, x], [xzzy, x, z, z, y], [xzzz, x, z, z, z], [yxxx, y, x, x, x], [yxxy, y, x, x, y], [yxxz, y, x, x, z], [yxyx, y, x, y, x], [yxyy, y, x, y, y],
+    [yxyz, y, x, y, z], [yxzx, y, x, z, x], [yxzy, y, x, z, y], [yxzz, y, x, z, z], [yyxx, y, y, x, x], [yyxy, y, y, x, y], [yyxz, y, y, x, z], [yyyx, y, y, y, x],
+    [yyyy, y, y, y, y], [yyyz, y, y, y, z], [yyzx, y, y, z, x], [yyzy, y, y, z, y], [yyzz, y, y, z, z], [yzxx, y, z, x, x], [yzxy, y, z, x, y], [yzxz, y, z, x, z],
+    [yzyx, y, z, y, x], [yzyy, y, z, y, y], [yzyz, y, z, y, z], [yzzx, y, z, z, x], [yzzy, y, z, z, y], [yzzz, y, z, z, z], [zxxx, z, x, x, x], [zxxy, z, x, x, y],
+    [zxxz, z, x, x, z], [zxyx, z, x, y, x], [zxyy, z, x, y, y], [zxyz, z, x, y, z], [zxzx, z, x, z, x], [zxzy, z, x, z, y], [zxzz, z, x, z, z], [zyxx, z, y, x, x],
+    [zyxy, z, y, x, y], [zyxz, z, y, x, z], [zyyx, z, y, y, x], [zyyy, z, y, y, y], [zyyz, z, y, y, z], [zyzx, z, y, z, x], [zyzy, z, y, z, y], [zyzz, z, y, z, z],
+    [zzxx, z, z, x, x], [zzxy, z, z, x, y], [zzxz, z, z, x, z], [zzyx, z, z, y, x], [zzyy, z, z, y, y], [zzyz, z, z, y, z], [zzzx, z, z, z, x], [zzzy, z, z, z, y],
+    [zzzz, z, z, z, z]
+);
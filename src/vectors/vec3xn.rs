@@ -0,0 +1,156 @@
+use super::{Vector3, VectorN};
+use crate::types::SupportedValue;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+// N 3D vectors stored as a structure of arrays, so every operation works on N vectors at once
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct Vector3xN<T, const N: usize> {
+    pub x: VectorN<T, N>,
+    pub y: VectorN<T, N>,
+    pub z: VectorN<T, N>,
+}
+
+// The common widths
+pub type Vector3x4<T> = Vector3xN<T, 4>;
+pub type Vector3x8<T> = Vector3xN<T, 8>;
+
+// Default
+impl<T: Default, const N: usize> Default for Vector3xN<T, N> {
+    fn default() -> Self {
+        Self {
+            x: VectorN::default(),
+            y: VectorN::default(),
+            z: VectorN::default(),
+        }
+    }
+}
+
+impl<T, const N: usize> Vector3xN<T, N> {
+    // Create a new wide vector out of its lanes
+    pub const fn new(x: VectorN<T, N>, y: VectorN<T, N>, z: VectorN<T, N>) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T: Copy, const N: usize> Vector3xN<T, N> {
+    // Create a wide vector with every lane set to the same vector
+    pub fn splat(vector: Vector3<T>) -> Self {
+        Self {
+            x: VectorN::new([vector.x; N]),
+            y: VectorN::new([vector.y; N]),
+            z: VectorN::new([vector.z; N]),
+        }
+    }
+    // Get the vector stored in a lane
+    pub fn lane(&self, lane: usize) -> Vector3<T> {
+        Vector3::new(self.x[lane], self.y[lane], self.z[lane])
+    }
+    // Overwrite the vector stored in a lane
+    pub fn set_lane(&mut self, lane: usize, vector: Vector3<T>) {
+        self.x[lane] = vector.x;
+        self.y[lane] = vector.y;
+        self.z[lane] = vector.z;
+    }
+}
+
+// Implement the vec3xn code
+impl<T: SupportedValue, const N: usize> Vector3xN<T, N> {
+    // Defaults
+    pub const ZERO: Self = Self::new(VectorN::ZERO, VectorN::ZERO, VectorN::ZERO);
+    pub const ONE: Self = Self::new(VectorN::ONE, VectorN::ONE, VectorN::ONE);
+}
+
+// Operations that work on any element type
+impl<T, const N: usize> Vector3xN<T, N>
+where
+    T: Copy + PartialOrd,
+{
+    // Get the min value between two vectors
+    pub fn min(self, other: Self) -> Self {
+        Self::new(self.x.min(other.x), self.y.min(other.y), self.z.min(other.z))
+    }
+    // Get the max value between two vectors
+    pub fn max(self, other: Self) -> Self {
+        Self::new(self.x.max(other.x), self.y.max(other.y), self.z.max(other.z))
+    }
+    // Clamp the current value between some bounds and return it
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        self.min(max).max(min)
+    }
+    // Equals
+    pub fn elem_eq(&self, other: &Self) -> Vector3xN<bool, N> {
+        Vector3xN::new(self.x.elem_eq(&other.x), self.y.elem_eq(&other.y), self.z.elem_eq(&other.z))
+    }
+    // Greater then
+    pub fn elem_gt(&self, other: &Self) -> Vector3xN<bool, N> {
+        Vector3xN::new(self.x.elem_gt(&other.x), self.y.elem_gt(&other.y), self.z.elem_gt(&other.z))
+    }
+    // Less than
+    pub fn elem_lt(&self, other: &Self) -> Vector3xN<bool, N> {
+        Vector3xN::new(self.x.elem_lt(&other.x), self.y.elem_lt(&other.y), self.z.elem_lt(&other.z))
+    }
+    // Greater than or equals
+    pub fn elem_gte(&self, other: &Self) -> Vector3xN<bool, N> {
+        Vector3xN::new(self.x.elem_gte(&other.x), self.y.elem_gte(&other.y), self.z.elem_gte(&other.z))
+    }
+    // Less than or equals
+    pub fn elem_lte(&self, other: &Self) -> Vector3xN<bool, N> {
+        Vector3xN::new(self.x.elem_lte(&other.x), self.y.elem_lte(&other.y), self.z.elem_lte(&other.z))
+    }
+}
+
+impl<const N: usize> Vector3xN<bool, N> {
+    // Use the same mask for all 3 elements of each lane, to select whole vectors
+    pub fn from_lane_mask(mask: VectorN<bool, N>) -> Self {
+        Self::new(mask, mask, mask)
+    }
+    // Return true if all the elements are true
+    pub fn all(&self) -> bool {
+        self.x.all() && self.y.all() && self.z.all()
+    }
+    // Return true if one or more elements are true
+    pub fn any(&self) -> bool {
+        self.x.any() || self.y.any() || self.z.any()
+    }
+    // Select between two vectors using the elements of the current bool vector
+    pub fn select<T: Copy>(&self, first: &Vector3xN<T, N>, second: &Vector3xN<T, N>) -> Vector3xN<T, N> {
+        Vector3xN::new(self.x.select(&first.x, &second.x), self.y.select(&first.y, &second.y), self.z.select(&first.z, &second.z))
+    }
+}
+
+// Operators
+crate::setup_vec3xn_operator!(Add, add, AddAssign, add_assign, +);
+crate::setup_vec3xn_operator!(Sub, sub, SubAssign, sub_assign, -);
+crate::setup_vec3xn_operator!(Mul, mul, MulAssign, mul_assign, *);
+crate::setup_vec3xn_operator!(Div, div, DivAssign, div_assign, /);
+
+impl<T, const N: usize> Neg for Vector3xN<T, N>
+where
+    T: Copy + Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+crate::setup_floating_vec3xn_operations!(f32);
+crate::setup_floating_vec3xn_operations!(f64);
+
+// Conversions from and to arrays of vectors
+impl<T: Copy, const N: usize> From<[Vector3<T>; N]> for Vector3xN<T, N> {
+    fn from(vectors: [Vector3<T>; N]) -> Self {
+        Self {
+            x: VectorN::new(vectors.map(|vector| vector.x)),
+            y: VectorN::new(vectors.map(|vector| vector.y)),
+            z: VectorN::new(vectors.map(|vector| vector.z)),
+        }
+    }
+}
+impl<T: Copy, const N: usize> From<Vector3xN<T, N>> for [Vector3<T>; N] {
+    fn from(vector: Vector3xN<T, N>) -> Self {
+        std::array::from_fn(|lane| vector.lane(lane))
+    }
+}
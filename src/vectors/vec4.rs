@@ -1,15 +1,16 @@
 use super::{Vector2, Vector3};
 use crate::{
     types::SupportedValue,
-    vector::{Swizzable, Vector, VectorElemCount},
+    vector::{IndexOutOfBounds, Swizzable, Vector, VectorElemCount},
 };
 use std::{
     fmt::{self},
     hash::Hash,
-    ops::{Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Not, Sub, SubAssign},
+    ops::{Add, AddAssign, BitAnd, BitOr, BitXor, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Not, Sub, SubAssign},
 };
 
-// A simple 4D vector, no simd support what-so-ever
+// A simple 4D vector. With the "simd" feature, Vector4<f32> uses SIMD for the dot product, length and normalization
+// The element wise operators stay scalar, so that they remain generic over the element type
 #[derive(PartialEq, Debug, Clone, Copy)]
 #[repr(C)]
 pub struct Vector4<T> {
@@ -116,12 +117,9 @@ impl<T> Index<usize> for Vector4<T> {
     type Output = T;
     // Index
     fn index(&self, index: usize) -> &Self::Output {
-        match index {
-            0 => &self.x,
-            1 => &self.y,
-            2 => &self.z,
-            3 => &self.w,
-            _ => todo!(),
+        match self.get(index) {
+            Some(value) => value,
+            None => panic!("{}", IndexOutOfBounds::new(index, 4, "Vector4")),
         }
     }
 }
@@ -130,12 +128,33 @@ impl<T> Index<usize> for Vector4<T> {
 impl<T> IndexMut<usize> for Vector4<T> {
     // Mut index
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match self.get_mut(index) {
+            Some(value) => value,
+            None => panic!("{}", IndexOutOfBounds::new(index, 4, "Vector4")),
+        }
+    }
+}
+
+// Non panicking indexer
+impl<T> Vector4<T> {
+    // Get an element, or None if the index is out of bounds
+    pub fn get(&self, index: usize) -> Option<&T> {
         match index {
-            0 => &mut self.x,
-            1 => &mut self.y,
-            2 => &mut self.z,
-            3 => &mut self.w,
-            _ => todo!(),
+            0 => Some(&self.x),
+            1 => Some(&self.y),
+            2 => Some(&self.z),
+            3 => Some(&self.w),
+            _ => None,
+        }
+    }
+    // Get an element mutably, or None if the index is out of bounds
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        match index {
+            0 => Some(&mut self.x),
+            1 => Some(&mut self.y),
+            2 => Some(&mut self.z),
+            3 => Some(&mut self.w),
+            _ => None,
         }
     }
 }
@@ -197,30 +216,20 @@ crate::impl_eq_hash!(Vector4<u64>);
 crate::impl_eq_hash!(Vector4<u128>);
 
 // Run the macros
-crate::setup_add!(Vector4<T>, T);
-crate::setup_sub!(Vector4<T>, T);
-crate::setup_mul!(Vector4<T>, T);
-crate::setup_div!(Vector4<T>, T);
-crate::setup_neg!(Vector4<T>, T);
-
-crate::setup_floating_vector_operations!(Vector4<f32>, T, f32);
-crate::setup_floating_vector_operations!(Vector4<f64>, T, f64);
-crate::setup_any_vector_operations!(Vector4<u8>, T, u8);
-crate::setup_any_vector_operations!(Vector4<u16>, T, u16);
-crate::setup_any_vector_operations!(Vector4<u32>, T, u32);
-crate::setup_any_vector_operations!(Vector4<u64>, T, u64);
-crate::setup_any_vector_operations!(Vector4<u128>, T, u128);
-crate::setup_any_vector_operations!(Vector4<usize>, T, usize);
-crate::setup_any_vector_operations!(Vector4<i8>, T, i8);
-crate::setup_any_vector_operations!(Vector4<i16>, T, i16);
-crate::setup_any_vector_operations!(Vector4<i32>, T, i32);
-crate::setup_any_vector_operations!(Vector4<i64>, T, i64);
-crate::setup_any_vector_operations!(Vector4<i128>, T, i128);
-crate::setup_any_vector_operations!(Vector4<isize>, T, isize);
-crate::setup_any_vector_operations!(Vector4<f32>, T, f32);
-crate::setup_any_vector_operations!(Vector4<f64>, T, f64);
-
-crate::impl_elem_wise_comparison!(Vector4<T>, T, Vector4<bool>);
+crate::setup_vector_operator!(Vector4<T>, Add, add, AddAssign, add_assign, +);
+crate::setup_vector_operator!(Vector4<T>, Sub, sub, SubAssign, sub_assign, -);
+crate::setup_vector_operator!(Vector4<T>, Mul, mul, MulAssign, mul_assign, *);
+crate::setup_vector_operator!(Vector4<T>, Div, div, DivAssign, div_assign, /);
+crate::setup_neg!(Vector4<T>);
+
+#[cfg(not(feature = "simd"))]
+crate::setup_floating_vector_operations!(Vector4<f32>, f32);
+#[cfg(feature = "simd")]
+crate::setup_floating_vector_operations!(Vector4<f32>, f32, simd);
+crate::setup_floating_vector_operations!(Vector4<f64>, f64);
+crate::setup_any_vector_operations!(Vector4<T>);
+
+crate::impl_elem_wise_comparison!(Vector4<T>, Vector4<bool>);
 
 // Dear lord
 // I deeply apologize for this
@@ -243,3 +252,61 @@ crate::impl_from_vec4!(Vector4<u64>, u64, i8, i16, i32, i64, i128, u8, u16, u32,
 crate::impl_from_vec4!(Vector4<u128>, u128, i8, i16, i32, i64, i128, u8, u16, u32, u64, f32, f64, usize, bool, isize);
 crate::impl_from_vec4!(Vector4<isize>, isize, i8, i16, i32, i64, i128, u8, u16, u32, u64, u128, f32, f64, bool, usize);
 crate::impl_from_vec4!(Vector4<usize>, usize, i8, i16, i32, i64, i128, u8, u16, u32, u64, u128, f32, f64, bool, isize);
+
+// Explicit endianness bytes
+// usize and isize are left out since their size depends on the platform, and bool since it has no byte encoding of its own
+crate::impl_endian_bytes!(Vector4, 4, f32, f64, i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+
+// Swizzles
+crate::impl_swizzle!(
+    Vector4<T>,
+    Vector2,
+    [xx, x, x], [xy, x, y], [xz, x, z], [xw, x, w], [yx, y, x], [yy, y, y], [yz, y, z], [yw, y, w], [zx, z, x], [zy, z, y], [zz, z, z], [zw, z, w], [wx, w, x], [wy, w, y],
+    [wz, w, z], [ww, w, w]
+);
+crate::impl_swizzle!(
+    Vector4<T>,
+    Vector3,
+    [xxx, x, x, x], [xxy, x, x, y], [xxz, x, x, z], [xxw, x, x, w], [xyx, x, y, x], [xyy, x, y, y], [xyz, x, y, z], [xyw, x, y, w], [xzx, x, z, x], [xzy, x, z, y], [xzz, x, z, z],
+    [xzw, x, z, w], [xwx, x, w, x], [xwy, x, w, y], [xwz, x, w, z], [xww, x, w, w], [yxx, y, x, x], [yxy, y, x, y], [yxz, y, x, z], [yxw, y, x, w], [yyx, y, y, x], [yyy, y, y, y],
+    [yyz, y, y, z], [yyw, y, y, w], [yzx, y, z, x], [yzy, y, z, y], [yzz, y, z, z], [yzw, y, z, w], [ywx, y, w, x], [ywy, y, w, y], [ywz, y, w, z], [yww, y, w, w], [zxx, z, x, x],
+    [zxy, z, x, y], [zxz, z, x, z], [zxw, z, x, w], [zyx, z, y, x], [zyy, z, y, y], [zyz, z, y, z], [zyw, z, y, w], [zzx, z, z, x], [zzy, z, z, y], [zzz, z, z, z], [zzw, z, z, w],
+    [zwx, z, w, x], [zwy, z, w, y], [zwz, z, w, z], [zww, z, w, w], [wxx, w, x, x], [wxy, w, x, y], [wxz, w, x, z], [wxw, w, x, w], [wyx, w, y, x], [wyy, w, y, y], [wyz, w, y, z],
+    [wyw, w, y, w], [wzx, w, z, x], [wzy, w, z, y], [wzz, w, z, z], [wzw, w, z, w], [wwx, w, w, x], [wwy, w, w, y], [wwz, w, w, z], [www, w, w, w]
+);
+crate::impl_swizzle!(
+    Vector4<T>,
+    Vector4,
+    [xxxx, x, x, x, x], [xxxy, x, x, x, y], [xxxz, x, x, x, z], [xxxw, x, x, x, w], [xxyx, x, x, y, x], [xxyy, x, x, y, y], [xxyz, x, x, y, z], [xxyw, x, x, y, w],
+    [xxzx, x, x, z, x], [xxzy, x, x, z, y], [xxzz, x, x, z, z], [xxzw, x, x, z, w], [xxwx, x, x, w, x], [xxwy, x, x, w, y], [xxwz, x, x, w, z], [xxww, x, x, w, w],
+    [xyxx, x, y, x, x], [xyxy, x, y, x, y], [xyxz, x, y, x, z], [xyxw, x, y, x, w], [xyyx, x, y, y, x], [xyyy, x, y, y, y], [xyyz, x, y, y, z], [xyyw, x, y, y, w],
+    [xyzx, x, y, z, x], [xyzy, x, y, z, y], [xyzz, x, y, z, z], [xyzw, x, y, z, w], [xywx, x, y, w, x], [xywy, x, y, w, y], [xywz, x, y, w, z], [xyww, x, y, w, w],
+    [xzxx, x, z, x, x], [xzxy, x, z, x, y], [xzxz, x, z, x, z], [xzxw, x, z, x, w], [xzyx, x, z, y, x], [xzyy, x, z, y, y], [xzyz, x, z, y, z], [xzyw, x, z, y, w],
+    [xzzx, x, z, z, x], [xzzy, x, z, z, y], [xzzz, x, z, z, z], [xzzw, x, z, z, w], [xzwx, x, z, w, x], [xzwy, x, z, w, y], [xzwz, x, z, w, z], [xzww, x, z, w, w],
+    [xwxx, x, w, x, x], [xwxy, x, w, x, y], [xwxz, x, w, x, z], [xwxw, x, w, x, w], [xwyx, x, w, y, x], [xwyy, x, w, y, y], [xwyz, x, w, y, z], [xwyw, x, w, y, w],
+    [xwzx, x, w, z, x], [xwzy, x, w, z, y], [xwzz, x, w, z, z], [xwzw, x, w, z, w], [xwwx, x, w, w, x], [xwwy, x, w, w, y], [xwwz, x, w, w, z], [xwww, x, w, w, w],
+    [yxxx, y, x, x, x], [yxxy, y, x, x, y], [yxxz, y, x, x, z], [yxxw, y, x, x, w], [yxyx, y, x, y, x], [yxyy, y, x, y, y], [yxyz, y, x, y, z], [yxyw, y, x, y, w],
+    [yxzx, y, x, z, x], [yxzy, y, x, z, y], [yxzz, y, x, z, z], [yxzw, y, x, z, w], [yxwx, y, x, w, x], [yxwy, y, x, w, y], [yxwz, y, x, w, z], [yxww, y, x, w, w],
+    [yyxx, y, y, x, x], [yyxy, y, y, x, y], [yyxz, y, y, x, z], [yyxw, y, y, x, w], [yyyx, y, y, y, x], [yyyy, y, y, y, y], [yyyz, y, y, y, z], [yyyw, y, y, y, w],
+    [yyzx, y, y, z, x], [yyzy, y, y, z, y], [yyzz, y, y, z, z], [yyzw, y, y, z, w], [yywx, y, y, w, x], [yywy, y, y, w, y], [yywz, y, y, w, z], [yyww, y, y, w, w],
+    [yzxx, y, z, x, x], [yzxy, y, z, x, y], [yzxz, y, z, x, z], [yzxw, y, z, x, w], [yzyx, y, z, y, x], [yzyy, y, z, y, y], [yzyz, y, z, y, z], [yzyw, y, z, y, w],
+    [yzzx, y, z, z, x], [yzzy, y, z, z, y], [yzzz, y, z, z, z], [yzzw, y, z, z, w], [yzwx, y, z, w, x], [yzwy, y, z, w, y], [yzwz, y, z, w, z], [yzww, y, z, w, w],
+    [ywxx, y, w, x, x], [ywxy, y, w, x, y], [ywxz, y, w, x, z], [ywxw, y, w, x, w], [ywyx, y, w, y, x], [ywyy, y, w, y, y], [ywyz, y, w, y, z], [ywyw, y, w, y, w],
+    [ywzx, y, w, z, x], [ywzy, y, w, z, y], [ywzz, y, w, z, z], [ywzw, y, w, z, w], [ywwx, y, w, w, x], [ywwy, y, w, w, y], [ywwz, y, w, w, z], [ywww, y, w, w, w],
+    [zxxx, z, x, x, x], [zxxy, z, x, x, y], [zxxz, z, x, x, z], [zxxw, z, x, x, w], [zxyx, z, x, y, x], [zxyy, z, x, y, y], [zxyz, z, x, y, z], [zxyw, z, x, y, w],
+    [zxzx, z, x, z, x], [zxzy, z, x, z, y], [zxzz, z, x, z, z], [zxzw, z, x, z, w], [zxwx, z, x, w, x], [zxwy, z, x, w, y], [zxwz, z, x, w, z], [zxww, z, x, w, w],
+    [zyxx, z, y, x, x], [zyxy, z, y, x, y], [zyxz, z, y, x, z], [zyxw, z, y, x, w], [zyyx, z, y, y, x], [zyyy, z, y, y, y], [zyyz, z, y, y, z], [zyyw, z, y, y, w],
+    [zyzx, z, y, z, x], [zyzy, z, y, z, y], [zyzz, z, y, z, z], [zyzw, z, y, z, w], [zywx, z, y, w, x], [zywy, z, y, w, y], [zywz, z, y, w, z], [zyww, z, y, w, w],
+    [zzxx, z, z, x, x], [zzxy, z, z, x, y], [zzxz, z, z, x, z], [zzxw, z, z, x, w], [zzyx, z, z, y, x], [zzyy, z, z, y, y], [zzyz, z, z, y, z], [zzyw, z, z, y, w],
+    [zzzx, z, z, z, x], [zzzy, z, z, z, y], [zzzz, z, z, z, z], [zzzw, z, z, z, w], [zzwx, z, z, w, x], [zzwy, z, z, w, y], [zzwz, z, z, w, z], [zzww, z, z, w, w],
+    [zwxx, z, w, x, x], [zwxy, z, w, x, y], [zwxz, z, w, x, z], [zwxw, z, w, x, w], [zwyx, z, w, y, x], [zwyy, z, w, y, y], [zwyz, z, w, y, z], [zwyw, z, w, y, w],
+    [zwzx, z, w, z, x], [zwzy, z, w, z, y], [zwzz, z, w, z, z], [zwzw, z, w, z, w], [zwwx, z, w, w, x], [zwwy, z, w, w, y], [zwwz, z, w, w, z], [zwww, z, w, w, w],
+    [wxxx, w, x, x, x], [wxxy, w, x, x, y], [wxxz, w, x, x, z], [wxxw, w, x, x, w], [wxyx, w, x, y, x], [wxyy, w, x, y, y], [wxyz, w, x, y, z], [wxyw, w, x, y, w],
+    [wxzx, w, x, z, x], [wxzy, w, x, z, y], [wxzz, w, x, z, z], [wxzw, w, x, z, w], [wxwx, w, x, w, x], [wxwy, w, x, w, y], [wxwz, w, x, w, z], [wxww, w, x, w, w],
+    [wyxx, w, y, x, x], [wyxy, w, y, x, y], [wyxz, w, y, x, z], [wyxw, w, y, x, w], [wyyx, w, y, y, x], [wyyy, w, y, y, y], [wyyz, w, y, y, z], [wyyw, w, y, y, w],
+    [wyzx, w, y, z, x], [wyzy, w, y, z, y], [wyzz, w, y, z, z], [wyzw, w, y, z, w], [wywx, w, y, w, x], [wywy, w, y, w, y], [wywz, w, y, w, z], [wyww, w, y, w, w],
+    [wzxx, w, z, x, x], [wzxy, w, z, x, y], [wzxz, w, z, x, z], [wzxw, w, z, x, w], [wzyx, w, z, y, x], [wzyy, w, z, y, y], [wzyz, w, z, y, z], [wzyw, w, z, y, w],
+    [wzzx, w, z, z, x], [wzzy, w, z, z, y], [wzzz, w, z, z, z], [wzzw, w, z, z, w], [wzwx, w, z, w, x], [wzwy, w, z, w, y], [wzwz, w, z, w, z], [wzww, w, z, w, w],
+    [wwxx, w, w, x, x], [wwxy, w, w, x, y], [wwxz, w, w, x, z], [wwxw, w, w, x, w], [wwyx, w, w, y, x], [wwyy, w, w, y, y], [wwyz, w, w, y, z], [wwyw, w, w, y, w],
+    [wwzx, w, w, z, x], [wwzy, w, w, z, y], [wwzz, w, w, z, z], [wwzw, w, w, z, w], [wwwx, w, w, w, x], [wwwy, w, w, w, y], [wwwz, w, w, w, z], [wwww, w, w, w, w]
+);
@@ -0,0 +1,257 @@
+use super::{Vector2, Vector3, Vector4};
+use crate::{
+    types::SupportedValue,
+    vector::{IndexOutOfBounds, Vector, VectorElemCount},
+};
+use std::{
+    fmt,
+    ops::{Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Not, Sub, SubAssign},
+};
+
+// A vector with any amount of elements, for when 2, 3 or 4 are not enough
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct VectorN<T, const N: usize> {
+    pub data: [T; N],
+}
+
+// Printing
+impl<T, const N: usize> fmt::Display for VectorN<T, N>
+where
+    T: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[")?;
+        for (i, value) in self.data.iter().enumerate() {
+            if i != 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", value)?;
+        }
+        write!(f, "]")
+    }
+}
+
+// Vector trait
+impl<T, const N: usize> Vector<T> for VectorN<T, N> {
+    fn as_ptr(&self) -> *const T {
+        self.data.as_ptr()
+    }
+
+    fn as_ptr_mut(&mut self) -> *mut T {
+        self.data.as_mut_ptr()
+    }
+}
+impl<T, const N: usize> VectorElemCount for VectorN<T, N> {
+    const ELEM_COUNT: usize = N;
+}
+impl<T, const N: usize> VectorElemCount for &VectorN<T, N> {
+    const ELEM_COUNT: usize = N;
+}
+
+// Default
+impl<T: Default, const N: usize> Default for VectorN<T, N> {
+    fn default() -> Self {
+        Self {
+            data: std::array::from_fn(|_| T::default()),
+        }
+    }
+}
+
+impl<T, const N: usize> VectorN<T, N> {
+    // Create a new vector out of its elements
+    pub const fn new(data: [T; N]) -> Self {
+        Self { data }
+    }
+    // Get an element, or None if the index is out of bounds
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.data.get(index)
+    }
+    // Get an element mutably, or None if the index is out of bounds
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.data.get_mut(index)
+    }
+}
+
+// Implement the vecn code
+impl<T: SupportedValue, const N: usize> VectorN<T, N> {
+    // Defaults
+    pub const ZERO: Self = Self { data: [T::ZERO; N] };
+    pub const ONE: Self = Self { data: [T::ONE; N] };
+    // Get the vector with only the "axis" element set to one
+    pub fn unit(axis: usize) -> Self {
+        let mut output = Self::ZERO;
+        output[axis] = T::ONE;
+        output
+    }
+}
+
+// Indexer
+impl<T, const N: usize> Index<usize> for VectorN<T, N> {
+    type Output = T;
+    // Index
+    fn index(&self, index: usize) -> &Self::Output {
+        match self.get(index) {
+            Some(value) => value,
+            None => panic!("{}", IndexOutOfBounds::new(index, N, "VectorN")),
+        }
+    }
+}
+
+// Mut indexer
+impl<T, const N: usize> IndexMut<usize> for VectorN<T, N> {
+    // Mut index
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match self.get_mut(index) {
+            Some(value) => value,
+            None => panic!("{}", IndexOutOfBounds::new(index, N, "VectorN")),
+        }
+    }
+}
+
+// Operations that work on any element type
+impl<T, const N: usize> VectorN<T, N>
+where
+    T: Copy + PartialOrd,
+{
+    // Get the min value between two vectors. Just like f32::min, NaN elements are ignored
+    pub fn min(self, other: Self) -> Self {
+        Self {
+            data: std::array::from_fn(|i| if other[i] < self[i] || is_nan(self[i]) { other[i] } else { self[i] }),
+        }
+    }
+    // Get the max value between two vectors. Just like f32::max, NaN elements are ignored
+    pub fn max(self, other: Self) -> Self {
+        Self {
+            data: std::array::from_fn(|i| if other[i] > self[i] || is_nan(self[i]) { other[i] } else { self[i] }),
+        }
+    }
+    // Clamp the current value between some bounds and return it
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        self.min(max).max(min)
+    }
+    // Equals
+    pub fn elem_eq(&self, other: &Self) -> VectorN<bool, N> {
+        VectorN::new(std::array::from_fn(|i| self[i] == other[i]))
+    }
+    // Greater then
+    pub fn elem_gt(&self, other: &Self) -> VectorN<bool, N> {
+        VectorN::new(std::array::from_fn(|i| self[i] > other[i]))
+    }
+    // Less than
+    pub fn elem_lt(&self, other: &Self) -> VectorN<bool, N> {
+        VectorN::new(std::array::from_fn(|i| self[i] < other[i]))
+    }
+    // Greater than or equals
+    pub fn elem_gte(&self, other: &Self) -> VectorN<bool, N> {
+        VectorN::new(std::array::from_fn(|i| self[i] >= other[i]))
+    }
+    // Less than or equals
+    pub fn elem_lte(&self, other: &Self) -> VectorN<bool, N> {
+        VectorN::new(std::array::from_fn(|i| self[i] <= other[i]))
+    }
+}
+
+// A value that cannot be compared with itself, which only happens with NaN
+fn is_nan<T: PartialOrd>(value: T) -> bool {
+    value.partial_cmp(&value).is_none()
+}
+
+impl<const N: usize> VectorN<bool, N> {
+    // Return true if all the elements are true
+    pub fn all(&self) -> bool {
+        self.data.iter().all(|x| *x)
+    }
+    // Return true if one or more elements are true
+    pub fn any(&self) -> bool {
+        self.data.iter().any(|x| *x)
+    }
+    // Select between two vectors using the elements of the current bool vector
+    pub fn select<T: Copy>(&self, first: &VectorN<T, N>, second: &VectorN<T, N>) -> VectorN<T, N> {
+        VectorN::new(std::array::from_fn(|i| if self[i] { first[i] } else { second[i] }))
+    }
+}
+
+// Operators
+crate::setup_vecn_operator!(Add, add, AddAssign, add_assign, +);
+crate::setup_vecn_operator!(Sub, sub, SubAssign, sub_assign, -);
+crate::setup_vecn_operator!(Mul, mul, MulAssign, mul_assign, *);
+crate::setup_vecn_operator!(Div, div, DivAssign, div_assign, /);
+crate::setup_vecn_operator!(BitAnd, bitand, BitAndAssign, bitand_assign, &);
+crate::setup_vecn_operator!(BitOr, bitor, BitOrAssign, bitor_assign, |);
+crate::setup_vecn_operator!(BitXor, bitxor, BitXorAssign, bitxor_assign, ^);
+
+impl<T, const N: usize> Neg for VectorN<T, N>
+where
+    T: Copy + Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(mut self) -> Self::Output {
+        for i in 0..N {
+            self[i] = -self[i];
+        }
+        self
+    }
+}
+
+impl<T, const N: usize> Not for VectorN<T, N>
+where
+    T: Copy + Not<Output = T>,
+{
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        Self::new(self.data.map(|x| !x))
+    }
+}
+
+crate::setup_floating_vecn_operations!(f32);
+crate::setup_floating_vecn_operations!(f64);
+
+// Conversions from and to arrays
+impl<T, const N: usize> From<[T; N]> for VectorN<T, N> {
+    fn from(data: [T; N]) -> Self {
+        Self { data }
+    }
+}
+impl<T, const N: usize> From<VectorN<T, N>> for [T; N] {
+    fn from(vector: VectorN<T, N>) -> Self {
+        vector.data
+    }
+}
+
+// Conversions from and to the named vector types
+impl<T> From<Vector2<T>> for VectorN<T, 2> {
+    fn from(vector: Vector2<T>) -> Self {
+        Self::new([vector.x, vector.y])
+    }
+}
+impl<T> From<VectorN<T, 2>> for Vector2<T> {
+    fn from(vector: VectorN<T, 2>) -> Self {
+        let [x, y] = vector.data;
+        Vector2::new(x, y)
+    }
+}
+impl<T> From<Vector3<T>> for VectorN<T, 3> {
+    fn from(vector: Vector3<T>) -> Self {
+        Self::new([vector.x, vector.y, vector.z])
+    }
+}
+impl<T> From<VectorN<T, 3>> for Vector3<T> {
+    fn from(vector: VectorN<T, 3>) -> Self {
+        let [x, y, z] = vector.data;
+        Vector3::new(x, y, z)
+    }
+}
+impl<T> From<Vector4<T>> for VectorN<T, 4> {
+    fn from(vector: Vector4<T>) -> Self {
+        Self::new([vector.x, vector.y, vector.z, vector.w])
+    }
+}
+impl<T> From<VectorN<T, 4>> for Vector4<T> {
+    fn from(vector: VectorN<T, 4>) -> Self {
+        let [x, y, z, w] = vector.data;
+        Vector4::new(x, y, z, w)
+    }
+}